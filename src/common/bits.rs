@@ -0,0 +1,228 @@
+use std::fmt::Display;
+
+use super::{Direction, Grid2d, VecI2};
+
+// A set of cells of a width x height grid, one bit per cell. Positions outside the grid are
+// never contained.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct BitGrid {
+    pub width: usize,
+    pub height: usize,
+    words: Vec<u64>,
+}
+
+impl BitGrid {
+    pub fn new(width: usize, height: usize) -> Self {
+        Self {
+            width,
+            height,
+            words: vec![0; (width * height).div_ceil(64)],
+        }
+    }
+
+    pub fn in_bounds(&self, pos: &VecI2) -> bool {
+        pos.0 >= 0 && pos.1 >= 0 && pos.0 < self.width as i32 && pos.1 < self.height as i32
+    }
+
+    fn to_index(&self, pos: &VecI2) -> Option<usize> {
+        if !self.in_bounds(pos) {
+            return None;
+        }
+        Some(pos.1 as usize * self.width + pos.0 as usize)
+    }
+
+    pub fn contains(&self, pos: &VecI2) -> bool {
+        self.to_index(pos)
+            .is_some_and(|i| self.words[i / 64] & (1 << (i % 64)) != 0)
+    }
+
+    // Returns whether the cell wasn't set before, panics outside the grid.
+    pub fn insert(&mut self, pos: &VecI2) -> bool {
+        let i = self
+            .to_index(pos)
+            .unwrap_or_else(|| panic!("{:?} is outside the grid", pos));
+        let word = &mut self.words[i / 64];
+        let bit = 1 << (i % 64);
+        let inserted = *word & bit == 0;
+        *word |= bit;
+        inserted
+    }
+
+    // Returns whether the cell was set.
+    pub fn remove(&mut self, pos: &VecI2) -> bool {
+        let Some(i) = self.to_index(pos) else {
+            return false;
+        };
+        let word = &mut self.words[i / 64];
+        let bit = 1 << (i % 64);
+        let removed = *word & bit != 0;
+        *word &= !bit;
+        removed
+    }
+
+    pub fn clear(&mut self) {
+        self.words.fill(0);
+    }
+
+    pub fn count(&self) -> usize {
+        self.words.iter().map(|w| w.count_ones() as usize).sum()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.words.iter().all(|w| *w == 0)
+    }
+
+    pub fn union_with(&mut self, other: &BitGrid) {
+        assert_eq!((self.width, self.height), (other.width, other.height));
+        for (a, b) in self.words.iter_mut().zip(&other.words) {
+            *a |= b;
+        }
+    }
+
+    pub fn intersect_with(&mut self, other: &BitGrid) {
+        assert_eq!((self.width, self.height), (other.width, other.height));
+        for (a, b) in self.words.iter_mut().zip(&other.words) {
+            *a &= b;
+        }
+    }
+
+    // The set cells in reading order.
+    pub fn iter(&self) -> impl Iterator<Item = VecI2> + '_ {
+        self.words.iter().enumerate().flat_map(move |(w, word)| {
+            let mut word = *word;
+            std::iter::from_fn(move || {
+                if word == 0 {
+                    return None;
+                }
+                let i = w * 64 + word.trailing_zeros() as usize;
+                word &= word - 1;
+                Some(VecI2((i % self.width) as i32, (i / self.width) as i32))
+            })
+        })
+    }
+
+    pub fn to_grid(&self) -> Grid2d<bool> {
+        let mut grid = Grid2d::new(
+            self.width,
+            self.height,
+            vec![false; self.width * self.height],
+        );
+        for pos in self.iter() {
+            grid.set(&pos, true);
+        }
+        grid
+    }
+}
+
+impl From<&Grid2d<bool>> for BitGrid {
+    fn from(grid: &Grid2d<bool>) -> Self {
+        let mut bits = BitGrid::new(grid.width, grid.height);
+        for (pos, _) in grid.iter().filter(|(_, b)| **b) {
+            bits.insert(&pos);
+        }
+        bits
+    }
+}
+
+impl Display for BitGrid {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for y in 0..self.height as i32 {
+            for x in 0..self.width as i32 {
+                let c = if self.contains(&VecI2(x, y)) {
+                    '#'
+                } else {
+                    '.'
+                };
+                write!(f, "{c}")?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+// Which directions each cell was visited in, as a 4 bit mask per cell. Useful for detecting
+// loops of something that moves and turns on a grid.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct DirectionGrid {
+    pub width: usize,
+    pub height: usize,
+    masks: Vec<u8>,
+}
+
+impl DirectionGrid {
+    pub fn new(width: usize, height: usize) -> Self {
+        Self {
+            width,
+            height,
+            masks: vec![0; width * height],
+        }
+    }
+
+    pub fn in_bounds(&self, pos: &VecI2) -> bool {
+        pos.0 >= 0 && pos.1 >= 0 && pos.0 < self.width as i32 && pos.1 < self.height as i32
+    }
+
+    fn to_index(&self, pos: &VecI2) -> Option<usize> {
+        if !self.in_bounds(pos) {
+            return None;
+        }
+        Some(pos.1 as usize * self.width + pos.0 as usize)
+    }
+
+    fn bit(direction: &Direction) -> u8 {
+        1 << *direction as u8
+    }
+
+    pub fn contains(&self, pos: &VecI2, direction: &Direction) -> bool {
+        self.to_index(pos)
+            .is_some_and(|i| self.masks[i] & Self::bit(direction) != 0)
+    }
+
+    // Whether the cell was visited in any direction.
+    pub fn visited(&self, pos: &VecI2) -> bool {
+        self.to_index(pos).is_some_and(|i| self.masks[i] != 0)
+    }
+
+    // Returns whether the cell wasn't visited in this direction before, panics outside the grid.
+    pub fn insert(&mut self, pos: &VecI2, direction: &Direction) -> bool {
+        let i = self
+            .to_index(pos)
+            .unwrap_or_else(|| panic!("{:?} is outside the grid", pos));
+        let inserted = self.masks[i] & Self::bit(direction) == 0;
+        self.masks[i] |= Self::bit(direction);
+        inserted
+    }
+
+    pub fn directions(&self, pos: &VecI2) -> impl Iterator<Item = Direction> {
+        let mask = self.to_index(pos).map_or(0, |i| self.masks[i]);
+        [
+            Direction::Left,
+            Direction::Right,
+            Direction::Up,
+            Direction::Down,
+        ]
+        .into_iter()
+        .filter(move |d| mask & Self::bit(d) != 0)
+    }
+
+    pub fn clear(&mut self) {
+        self.masks.fill(0);
+    }
+
+    pub fn union_with(&mut self, other: &DirectionGrid) {
+        assert_eq!((self.width, self.height), (other.width, other.height));
+        for (a, b) in self.masks.iter_mut().zip(&other.masks) {
+            *a |= b;
+        }
+    }
+
+    // The cells visited in any direction.
+    pub fn visited_cells(&self) -> BitGrid {
+        let mut bits = BitGrid::new(self.width, self.height);
+        for (i, _) in self.masks.iter().enumerate().filter(|(_, m)| **m != 0) {
+            bits.insert(&VecI2((i % self.width) as i32, (i / self.width) as i32));
+        }
+        bits
+    }
+}
@@ -0,0 +1,74 @@
+use crate::geometry::{Bounds2, Extendable};
+
+use super::{region::Region, Connectivity, Grid2d, VecI2};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Component {
+    pub label: usize,
+    pub area: usize,
+    pub bounds: Bounds2,
+    // in reading order
+    pub cells: Vec<VecI2>,
+}
+
+impl Component {
+    pub fn region(&self) -> Region {
+        Region::new(self.cells.iter().copied())
+    }
+}
+
+const UNLABELLED: usize = usize::MAX;
+
+impl<T> Grid2d<T> {
+    // 4-connected regions of equal cells
+    pub fn components(&self) -> (Grid2d<usize>, Vec<Component>)
+    where
+        T: PartialEq,
+    {
+        self.label_components(Connectivity::Four, |a, b| a == b)
+    }
+
+    // Neighboring cells belong to the same component if `same` holds for their values. Returns a
+    // grid with the label of every cell, labels are indices into the list of components.
+    pub fn label_components(
+        &self,
+        connectivity: Connectivity,
+        same: impl Fn(&T, &T) -> bool,
+    ) -> (Grid2d<usize>, Vec<Component>) {
+        let mut labels = Grid2d::new(self.width, self.height, vec![UNLABELLED; self.vec.len()]);
+        let mut components = vec![];
+        let mut stack = vec![];
+
+        for (start, value) in self.iter() {
+            if labels.get(&start) != Some(&UNLABELLED) {
+                continue;
+            }
+            let label = components.len();
+            labels.set(&start, label);
+            stack.push((start, value));
+
+            let mut cells = vec![];
+            let mut bounds = Bounds2::from_point(start);
+            while let Some((pos, value)) = stack.pop() {
+                cells.push(pos);
+                bounds.extend_mut(&Bounds2::from_point(pos));
+                for (next, next_value) in self.neighbors(&pos, connectivity) {
+                    let next_label = labels.get_mut(&next).unwrap();
+                    if *next_label == UNLABELLED && same(value, next_value) {
+                        *next_label = label;
+                        stack.push((next, next_value));
+                    }
+                }
+            }
+            cells.sort_unstable_by_key(|pos| (pos.1, pos.0));
+
+            components.push(Component {
+                label,
+                area: cells.len(),
+                bounds,
+                cells,
+            });
+        }
+        (labels, components)
+    }
+}
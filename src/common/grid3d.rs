@@ -0,0 +1,148 @@
+use std::fmt::Display;
+
+use crate::geometry::{Bounded3, Bounds3};
+
+use super::{VecI3, NEIGHBORS6};
+
+// Cells are stored layer by layer, each layer in reading order.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Grid3d<T> {
+    pub width: usize,
+    pub height: usize,
+    pub depth: usize,
+    pub vec: Vec<T>,
+}
+
+impl<T> Grid3d<T> {
+    pub fn new(width: usize, height: usize, depth: usize, vec: Vec<T>) -> Self {
+        assert_eq!(vec.len(), width * height * depth, "Grid3d size mismatch");
+        Self {
+            width,
+            height,
+            depth,
+            vec,
+        }
+    }
+
+    pub fn filled(width: usize, height: usize, depth: usize, value: T) -> Self
+    where
+        T: Clone,
+    {
+        Self::new(width, height, depth, vec![value; width * height * depth])
+    }
+
+    // A grid that covers the bounds, positions are shifted so that the minimum becomes 0.
+    pub fn for_bounds(bounds: &Bounds3, value: T) -> (Self, VecI3)
+    where
+        T: Clone,
+    {
+        let size = bounds.size();
+        let grid = Self::filled(
+            size.0 as usize + 1,
+            size.1 as usize + 1,
+            size.2 as usize + 1,
+            value,
+        );
+        (grid, bounds.min())
+    }
+
+    pub fn in_bounds(&self, pos: &VecI3) -> bool {
+        pos.0 >= 0
+            && pos.1 >= 0
+            && pos.2 >= 0
+            && pos.0 < self.width as i32
+            && pos.1 < self.height as i32
+            && pos.2 < self.depth as i32
+    }
+
+    pub fn to_index(&self, pos: &VecI3) -> Option<usize> {
+        if !self.in_bounds(pos) {
+            return None;
+        }
+        Some((pos.2 as usize * self.height + pos.1 as usize) * self.width + pos.0 as usize)
+    }
+
+    pub fn to_pos(&self, index: usize) -> Option<VecI3> {
+        if index >= self.vec.len() {
+            return None;
+        }
+        let x = index % self.width;
+        let y = index / self.width % self.height;
+        let z = index / (self.width * self.height);
+        Some(VecI3(x as i32, y as i32, z as i32))
+    }
+
+    pub fn get(&self, pos: &VecI3) -> Option<&T> {
+        let index = self.to_index(pos)?;
+        self.vec.get(index)
+    }
+
+    pub fn get_mut(&mut self, pos: &VecI3) -> Option<&mut T> {
+        let index = self.to_index(pos)?;
+        self.vec.get_mut(index)
+    }
+
+    pub fn set(&mut self, pos: &VecI3, value: T) -> Option<()> {
+        *self.get_mut(pos)? = value;
+        Some(())
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (VecI3, &T)> {
+        self.vec
+            .iter()
+            .enumerate()
+            .map(|(i, t)| (self.to_pos(i).unwrap(), t))
+    }
+
+    // The face neighbors inside the grid.
+    pub fn neighbors6(&self, pos: &VecI3) -> impl Iterator<Item = (VecI3, &T)> {
+        let pos = *pos;
+        NEIGHBORS6.iter().filter_map(move |offset| {
+            let next = pos + *offset;
+            self.get(&next).map(|t| (next, t))
+        })
+    }
+
+    // All cells reachable from `start` through face neighbors, including `start`, in the order
+    // they are reached. Empty if `start` itself isn't passable.
+    pub fn flood_fill(&self, start: &VecI3, passable: impl Fn(&T) -> bool) -> Vec<VecI3> {
+        let mut visited = vec![false; self.vec.len()];
+        let mut cells = vec![];
+        match self.to_index(start) {
+            Some(index) if passable(&self.vec[index]) => visited[index] = true,
+            _ => return cells,
+        }
+        let mut stack = vec![*start];
+        while let Some(pos) = stack.pop() {
+            cells.push(pos);
+            for (next, t) in self.neighbors6(&pos) {
+                let index = self.to_index(&next).unwrap();
+                if !visited[index] && passable(t) {
+                    visited[index] = true;
+                    stack.push(next);
+                }
+            }
+        }
+        cells
+    }
+}
+
+// Layers from z = 0 up, separated by an empty line.
+impl<T: Display> Display for Grid3d<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for z in 0..self.depth {
+            if z > 0 {
+                writeln!(f)?;
+            }
+            writeln!(f, "z={}", z)?;
+            for y in 0..self.height {
+                for x in 0..self.width {
+                    let pos = VecI3(x as i32, y as i32, z as i32);
+                    write!(f, "{}", self.get(&pos).unwrap())?;
+                }
+                writeln!(f)?;
+            }
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,395 @@
+use std::ops::{Add, Mul, Sub};
+
+use rustc_hash::FxHashMap;
+
+use super::{Grid2d, VecI2};
+
+// Axial hex coordinates. The third cube coordinate is implied, q + r + s = 0.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Hex {
+    pub q: i32,
+    pub r: i32,
+}
+
+// Directions of hexes with a pointy top, where neighbors share vertical edges.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum PointyDirection {
+    East,
+    NorthEast,
+    NorthWest,
+    West,
+    SouthWest,
+    SouthEast,
+}
+
+// Directions of hexes with a flat top, where neighbors share horizontal edges.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum FlatDirection {
+    North,
+    NorthEast,
+    SouthEast,
+    South,
+    SouthWest,
+    NorthWest,
+}
+
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum HexLayout {
+    Pointy,
+    Flat,
+}
+
+// Counterclockwise on screen, starting east.
+const OFFSETS: [Hex; 6] = [
+    Hex { q: 1, r: 0 },
+    Hex { q: 1, r: -1 },
+    Hex { q: 0, r: -1 },
+    Hex { q: -1, r: 0 },
+    Hex { q: -1, r: 1 },
+    Hex { q: 0, r: 1 },
+];
+
+impl Hex {
+    pub fn new(q: i32, r: i32) -> Self {
+        Self { q, r }
+    }
+
+    pub fn from_cube(q: i32, r: i32, s: i32) -> Self {
+        assert_eq!(q + r + s, 0, "Invalid cube coordinates {} {} {}", q, r, s);
+        Self { q, r }
+    }
+
+    pub fn s(&self) -> i32 {
+        -self.q - self.r
+    }
+
+    pub fn cube(&self) -> (i32, i32, i32) {
+        (self.q, self.r, self.s())
+    }
+
+    pub fn distance(&self, other: &Hex) -> i32 {
+        let d = *self - *other;
+        (d.q.abs() + d.r.abs() + d.s().abs()) / 2
+    }
+
+    pub fn neighbors(&self) -> [Hex; 6] {
+        OFFSETS.map(|offset| *self + offset)
+    }
+
+    // The hexes at exactly `radius` steps, going around counterclockwise.
+    pub fn ring(&self, radius: i32) -> Vec<Hex> {
+        if radius == 0 {
+            return vec![*self];
+        }
+        let mut hex = *self + OFFSETS[4] * radius;
+        let mut ring = Vec::with_capacity(6 * radius as usize);
+        for offset in OFFSETS {
+            for _ in 0..radius {
+                ring.push(hex);
+                hex = hex + offset;
+            }
+        }
+        ring
+    }
+
+    // The hexes within `radius` steps, ring by ring from the center outwards.
+    pub fn spiral(&self, radius: i32) -> Vec<Hex> {
+        (0..=radius).flat_map(|r| self.ring(r)).collect()
+    }
+
+    // Offset coordinates of a pointy layout where odd rows are shifted right.
+    pub fn to_odd_r(self) -> VecI2 {
+        VecI2(self.q + (self.r - (self.r & 1)) / 2, self.r)
+    }
+
+    pub fn from_odd_r(pos: &VecI2) -> Self {
+        Self::new(pos.0 - (pos.1 - (pos.1 & 1)) / 2, pos.1)
+    }
+
+    // Offset coordinates of a flat layout where odd columns are shifted down.
+    pub fn to_odd_q(self) -> VecI2 {
+        VecI2(self.q, self.r + (self.q - (self.q & 1)) / 2)
+    }
+
+    pub fn from_odd_q(pos: &VecI2) -> Self {
+        Self::new(pos.0, pos.1 - (pos.0 - (pos.0 & 1)) / 2)
+    }
+
+    // Doubled coordinates, where every hex gets its own character cell when printed.
+    pub fn to_doubled(self, layout: HexLayout) -> VecI2 {
+        match layout {
+            HexLayout::Pointy => VecI2(2 * self.q + self.r, self.r),
+            HexLayout::Flat => VecI2(self.q, 2 * self.r + self.q),
+        }
+    }
+}
+
+impl Add for Hex {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        Self::new(self.q + rhs.q, self.r + rhs.r)
+    }
+}
+
+impl Sub for Hex {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self {
+        Self::new(self.q - rhs.q, self.r - rhs.r)
+    }
+}
+
+impl Mul<i32> for Hex {
+    type Output = Self;
+
+    fn mul(self, rhs: i32) -> Self {
+        Self::new(self.q * rhs, self.r * rhs)
+    }
+}
+
+impl PointyDirection {
+    pub const ALL: [PointyDirection; 6] = [
+        PointyDirection::East,
+        PointyDirection::NorthEast,
+        PointyDirection::NorthWest,
+        PointyDirection::West,
+        PointyDirection::SouthWest,
+        PointyDirection::SouthEast,
+    ];
+
+    pub fn offset(&self) -> Hex {
+        OFFSETS[*self as usize]
+    }
+
+    // In either case.
+    pub fn parse(s: &str) -> Option<Self> {
+        match s.to_ascii_lowercase().as_str() {
+            "e" => Some(Self::East),
+            "ne" => Some(Self::NorthEast),
+            "nw" => Some(Self::NorthWest),
+            "w" => Some(Self::West),
+            "sw" => Some(Self::SouthWest),
+            "se" => Some(Self::SouthEast),
+            _ => None,
+        }
+    }
+
+    // Both "ne,e,sw" and "NEESW" work.
+    pub fn parse_path(s: &str) -> Option<Vec<Self>> {
+        parse_path(s, Self::parse)
+    }
+}
+
+impl FlatDirection {
+    pub const ALL: [FlatDirection; 6] = [
+        FlatDirection::North,
+        FlatDirection::NorthEast,
+        FlatDirection::SouthEast,
+        FlatDirection::South,
+        FlatDirection::SouthWest,
+        FlatDirection::NorthWest,
+    ];
+
+    pub fn offset(&self) -> Hex {
+        match self {
+            FlatDirection::North => Hex::new(0, -1),
+            FlatDirection::NorthEast => Hex::new(1, -1),
+            FlatDirection::SouthEast => Hex::new(1, 0),
+            FlatDirection::South => Hex::new(0, 1),
+            FlatDirection::SouthWest => Hex::new(-1, 1),
+            FlatDirection::NorthWest => Hex::new(-1, 0),
+        }
+    }
+
+    // In either case.
+    pub fn parse(s: &str) -> Option<Self> {
+        match s.to_ascii_lowercase().as_str() {
+            "n" => Some(Self::North),
+            "ne" => Some(Self::NorthEast),
+            "se" => Some(Self::SouthEast),
+            "s" => Some(Self::South),
+            "sw" => Some(Self::SouthWest),
+            "nw" => Some(Self::NorthWest),
+            _ => None,
+        }
+    }
+
+    // Both "ne,n,sw" and "nensw" work.
+    pub fn parse_path(s: &str) -> Option<Vec<Self>> {
+        parse_path(s, Self::parse)
+    }
+}
+
+// Splits on commas and whitespace, and between directions written without separators by
+// preferring two letter directions.
+fn parse_path<D>(s: &str, parse: impl Fn(&str) -> Option<D>) -> Option<Vec<D>> {
+    let mut path = vec![];
+    for part in s.split(|c: char| c == ',' || c.is_whitespace()) {
+        let mut rest = part.trim();
+        while !rest.is_empty() {
+            let two = rest.get(..2).and_then(&parse);
+            let (direction, len) = match two {
+                Some(direction) => (direction, 2),
+                None => (parse(rest.get(..1)?)?, 1),
+            };
+            path.push(direction);
+            rest = &rest[len..];
+        }
+    }
+    Some(path)
+}
+
+// A sparse container of hexes.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HexGrid<T> {
+    cells: FxHashMap<Hex, T>,
+}
+
+impl<T> Default for HexGrid<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> HexGrid<T> {
+    pub fn new() -> Self {
+        Self {
+            cells: FxHashMap::default(),
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.cells.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    pub fn contains(&self, hex: &Hex) -> bool {
+        self.cells.contains_key(hex)
+    }
+
+    pub fn get(&self, hex: &Hex) -> Option<&T> {
+        self.cells.get(hex)
+    }
+
+    pub fn get_mut(&mut self, hex: &Hex) -> Option<&mut T> {
+        self.cells.get_mut(hex)
+    }
+
+    pub fn set(&mut self, hex: &Hex, value: T) {
+        self.cells.insert(*hex, value);
+    }
+
+    // Like `set`, but returns the previous value.
+    pub fn insert(&mut self, hex: &Hex, value: T) -> Option<T> {
+        self.cells.insert(*hex, value)
+    }
+
+    pub fn remove(&mut self, hex: &Hex) -> Option<T> {
+        self.cells.remove(hex)
+    }
+
+    // In no particular order.
+    pub fn iter(&self) -> impl Iterator<Item = (Hex, &T)> {
+        self.cells.iter().map(|(hex, t)| (*hex, t))
+    }
+
+    // The occupied neighbors.
+    pub fn neighbors(&self, hex: &Hex) -> impl Iterator<Item = (Hex, &T)> {
+        hex.neighbors()
+            .into_iter()
+            .filter_map(|next| self.get(&next).map(|t| (next, t)))
+    }
+
+    // Draws the hexes in doubled coordinates, shifted to start at (0, 0). Cells between the
+    // hexes are filled with `empty`.
+    pub fn to_grid(
+        &self,
+        layout: HexLayout,
+        glyph: impl Fn(&T) -> char,
+        empty: char,
+    ) -> Grid2d<char> {
+        let positions = self
+            .iter()
+            .map(|(hex, t)| (hex.to_doubled(layout), t))
+            .collect::<Vec<_>>();
+        let Some(min) = positions
+            .iter()
+            .map(|(p, _)| *p)
+            .reduce(|a, b| VecI2::min(&a, &b))
+        else {
+            return Grid2d::new(0, 0, vec![]);
+        };
+        let max = positions
+            .iter()
+            .map(|(p, _)| *p)
+            .reduce(|a, b| VecI2::max(&a, &b))
+            .unwrap();
+        let width = (max.0 - min.0 + 1) as usize;
+        let height = (max.1 - min.1 + 1) as usize;
+        let mut grid = Grid2d::new(width, height, vec![empty; width * height]);
+        for (pos, t) in positions {
+            grid.set(&(pos - min), glyph(t));
+        }
+        grid
+    }
+}
+
+impl<T> FromIterator<(Hex, T)> for HexGrid<T> {
+    fn from_iter<I: IntoIterator<Item = (Hex, T)>>(iter: I) -> Self {
+        Self {
+            cells: iter.into_iter().collect(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rings_and_distances() {
+        let center = Hex::default();
+        assert_eq!(center.ring(0), vec![center]);
+        assert_eq!(center.ring(2).len(), 12);
+        assert!(center.ring(3).iter().all(|hex| hex.distance(&center) == 3));
+        assert_eq!(center.spiral(2).len(), 19);
+    }
+
+    #[test]
+    fn offset_coordinates_round_trip() {
+        for hex in Hex::default().spiral(4) {
+            assert_eq!(Hex::from_odd_r(&hex.to_odd_r()), hex);
+            assert_eq!(Hex::from_odd_q(&hex.to_odd_q()), hex);
+        }
+    }
+
+    #[test]
+    fn parse_paths() {
+        use PointyDirection::*;
+        assert_eq!(
+            PointyDirection::parse_path("esenee"),
+            Some(vec![East, SouthEast, NorthEast, East])
+        );
+        assert_eq!(PointyDirection::parse_path("NE, e,sw").unwrap().len(), 3);
+        assert_eq!(PointyDirection::parse_path("nn"), None);
+
+        let back = PointyDirection::parse_path("nwwswee").unwrap();
+        let end = back.iter().fold(Hex::default(), |hex, d| hex + d.offset());
+        assert_eq!(end, Hex::default());
+    }
+
+    #[test]
+    fn draw_pointy() {
+        let grid: HexGrid<char> = Hex::default()
+            .spiral(1)
+            .into_iter()
+            .map(|hex| (hex, 'x'))
+            .collect();
+        let drawn = grid.to_grid(HexLayout::Pointy, |c| *c, ' ');
+        assert_eq!(drawn.to_string(), " x x \nx x x\n x x \n");
+    }
+}
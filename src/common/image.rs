@@ -0,0 +1,264 @@
+use std::{
+    fs,
+    io::{self, ErrorKind},
+    path::{Path, PathBuf},
+};
+
+use super::{FrameSink, Grid2d};
+
+pub type Rgb = [u8; 3];
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Image {
+    pub width: usize,
+    pub height: usize,
+    pub pixels: Vec<Rgb>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ImageFormat {
+    Ppm,
+    Png,
+}
+
+impl ImageFormat {
+    pub fn extension(&self) -> &'static str {
+        match self {
+            ImageFormat::Ppm => "ppm",
+            ImageFormat::Png => "png",
+        }
+    }
+}
+
+impl<T> Grid2d<T> {
+    // One pixel per cell.
+    pub fn to_image(&self, color: impl Fn(&T) -> Rgb) -> Image {
+        Image {
+            width: self.width,
+            height: self.height,
+            pixels: self.vec.iter().map(color).collect(),
+        }
+    }
+}
+
+impl Image {
+    pub fn scaled(&self, factor: usize) -> Image {
+        let width = self.width * factor;
+        let pixels = (0..self.height * factor)
+            .flat_map(|y| (0..width).map(move |x| (x / factor, y / factor)))
+            .map(|(x, y)| self.pixels[y * self.width + x])
+            .collect();
+        Image {
+            width,
+            height: self.height * factor,
+            pixels,
+        }
+    }
+
+    // Binary PPM (P6).
+    pub fn to_ppm(&self) -> Vec<u8> {
+        let mut bytes = format!("P6\n{} {}\n255\n", self.width, self.height).into_bytes();
+        bytes.extend(self.pixels.iter().flatten());
+        bytes
+    }
+
+    // 8 bit RGB PNG. The image data is stored without compression, so no deflate implementation
+    // is needed.
+    pub fn to_png(&self) -> Vec<u8> {
+        let mut raw = Vec::with_capacity(self.height * (1 + self.width * 3));
+        for row in self.pixels.chunks(self.width.max(1)).take(self.height) {
+            // filter type None
+            raw.push(0);
+            raw.extend(row.iter().flatten());
+        }
+
+        let mut zlib = vec![0x78, 0x01];
+        let mut blocks = raw.chunks(0xffff).peekable();
+        if blocks.peek().is_none() {
+            zlib.extend([1, 0, 0, 0xff, 0xff]);
+        }
+        while let Some(block) = blocks.next() {
+            let last = blocks.peek().is_none();
+            let len = block.len() as u16;
+            zlib.push(last as u8);
+            zlib.extend(len.to_le_bytes());
+            zlib.extend((!len).to_le_bytes());
+            zlib.extend(block);
+        }
+        zlib.extend(adler32(&raw).to_be_bytes());
+
+        let mut header = vec![];
+        header.extend((self.width as u32).to_be_bytes());
+        header.extend((self.height as u32).to_be_bytes());
+        // bit depth, color type RGB, compression, filter and interlace method
+        header.extend([8, 2, 0, 0, 0]);
+
+        let mut bytes = vec![0x89, b'P', b'N', b'G', b'\r', b'\n', 0x1a, b'\n'];
+        write_chunk(&mut bytes, b"IHDR", &header);
+        write_chunk(&mut bytes, b"IDAT", &zlib);
+        write_chunk(&mut bytes, b"IEND", &[]);
+        bytes
+    }
+
+    pub fn encode(&self, format: ImageFormat) -> Vec<u8> {
+        match format {
+            ImageFormat::Ppm => self.to_ppm(),
+            ImageFormat::Png => self.to_png(),
+        }
+    }
+
+    // The format is taken from the file extension.
+    pub fn save(&self, path: impl AsRef<Path>) -> io::Result<()> {
+        let path = path.as_ref();
+        let format = match path.extension().and_then(|e| e.to_str()) {
+            Some("ppm") => ImageFormat::Ppm,
+            Some("png") => ImageFormat::Png,
+            _ => {
+                return Err(io::Error::new(
+                    ErrorKind::InvalidInput,
+                    format!("Unknown image format: {}", path.display()),
+                ))
+            }
+        };
+        fs::write(path, self.encode(format))
+    }
+}
+
+fn write_chunk(bytes: &mut Vec<u8>, kind: &[u8; 4], data: &[u8]) {
+    bytes.extend((data.len() as u32).to_be_bytes());
+    let start = bytes.len();
+    bytes.extend(kind);
+    bytes.extend(data);
+    let crc = crc32(&bytes[start..]);
+    bytes.extend(crc.to_be_bytes());
+}
+
+fn crc32(data: &[u8]) -> u32 {
+    let mut crc = !0u32;
+    for byte in data {
+        crc ^= *byte as u32;
+        for _ in 0..8 {
+            crc = if crc & 1 != 0 {
+                (crc >> 1) ^ 0xedb8_8320
+            } else {
+                crc >> 1
+            };
+        }
+    }
+    !crc
+}
+
+fn adler32(data: &[u8]) -> u32 {
+    let (mut a, mut b) = (1u32, 0u32);
+    for byte in data {
+        a = (a + *byte as u32) % 65521;
+        b = (b + a) % 65521;
+    }
+    (b << 16) | a
+}
+
+// Colors for the characters used in the puzzle maps, anything else is drawn in green.
+pub fn default_palette(c: &char) -> Rgb {
+    match c {
+        '.' | ' ' => [0, 0, 0],
+        '#' => [128, 128, 128],
+        'O' | '[' | ']' => [160, 110, 40],
+        '@' | '^' | '>' | 'v' | '<' => [230, 40, 40],
+        'X' => [60, 90, 200],
+        _ => [40, 200, 60],
+    }
+}
+
+// Writes every frame to `image_00000.png`, `image_00001.png`, ... in a directory.
+pub struct FrameWriter {
+    dir: PathBuf,
+    format: ImageFormat,
+    color: fn(&char) -> Rgb,
+    scale: usize,
+    next: usize,
+}
+
+impl FrameWriter {
+    pub fn new(dir: impl AsRef<Path>, format: ImageFormat) -> io::Result<Self> {
+        fs::create_dir_all(&dir)?;
+        Ok(Self {
+            dir: dir.as_ref().to_path_buf(),
+            format,
+            color: default_palette,
+            scale: 1,
+            next: 0,
+        })
+    }
+
+    pub fn with_palette(self, color: fn(&char) -> Rgb) -> Self {
+        Self { color, ..self }
+    }
+
+    pub fn with_scale(self, scale: usize) -> Self {
+        Self { scale, ..self }
+    }
+
+    pub fn count(&self) -> usize {
+        self.next
+    }
+
+    pub fn write(&mut self, image: &Image) -> io::Result<PathBuf> {
+        let path = self.dir.join(format!(
+            "image_{:05}.{}",
+            self.next,
+            self.format.extension()
+        ));
+        fs::write(&path, image.encode(self.format))?;
+        self.next += 1;
+        Ok(path)
+    }
+}
+
+impl FrameSink for FrameWriter {
+    fn frame(&mut self, frame: &Grid2d<char>) {
+        let image = frame.to_image(self.color);
+        let image = if self.scale > 1 {
+            image.scaled(self.scale)
+        } else {
+            image
+        };
+        if let Err(e) = self.write(&image) {
+            panic!("Could not write frame to {}: {}", self.dir.display(), e);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn checksums() {
+        assert_eq!(crc32(b"IEND"), 0xae42_6082);
+        assert_eq!(adler32(b"Wikipedia"), 0x11e6_0398);
+    }
+
+    #[test]
+    fn png_bytes() {
+        let image = Image {
+            width: 2,
+            height: 1,
+            pixels: vec![[230, 40, 40], [60, 90, 200]],
+        };
+        // Checked against zlib's crc32 and adler32, the data decompresses with zlib.
+        #[rustfmt::skip]
+        let expected = [
+            0x89, 0x50, 0x4e, 0x47, 0x0d, 0x0a, 0x1a, 0x0a,
+            0x00, 0x00, 0x00, 0x0d, 0x49, 0x48, 0x44, 0x52,
+            0x00, 0x00, 0x00, 0x02, 0x00, 0x00, 0x00, 0x01, 0x08, 0x02, 0x00, 0x00, 0x00,
+            0x7b, 0x40, 0xe8, 0xdd,
+            0x00, 0x00, 0x00, 0x12, 0x49, 0x44, 0x41, 0x54,
+            0x78, 0x01, 0x01, 0x07, 0x00, 0xf8, 0xff,
+            0x00, 0xe6, 0x28, 0x28, 0x3c, 0x5a, 0xc8,
+            0x09, 0x03, 0x02, 0x95,
+            0x28, 0x33, 0xdb, 0x73,
+            0x00, 0x00, 0x00, 0x00, 0x49, 0x45, 0x4e, 0x44, 0xae, 0x42, 0x60, 0x82,
+        ];
+        assert_eq!(image.to_png(), expected);
+    }
+}
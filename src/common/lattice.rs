@@ -0,0 +1,49 @@
+use super::VecI2;
+
+impl VecI2 {
+    // Every lattice point on the segment to `end`, both ends included.
+    pub fn lattice_line(&self, end: &VecI2) -> impl Iterator<Item = VecI2> {
+        let start = *self;
+        let step = (*end - start).reduced();
+        let steps = match step {
+            VecI2(0, 0) => 0,
+            VecI2(0, y) => (end.1 - start.1) / y,
+            VecI2(x, _) => (end.0 - start.0) / x,
+        };
+        (0..=steps).map(move |i| start + step * i)
+    }
+
+    // The cells a straight line to `end` passes closest to, both ends included. Consecutive
+    // cells are 8-connected.
+    pub fn bresenham(&self, end: &VecI2) -> Vec<VecI2> {
+        let dx = (end.0 - self.0).abs();
+        let dy = -(end.1 - self.1).abs();
+        let sx = (end.0 - self.0).signum();
+        let sy = (end.1 - self.1).signum();
+        let mut err = dx + dy;
+        let mut pos = *self;
+        let mut line = vec![pos];
+        while pos != *end {
+            let e2 = 2 * err;
+            if e2 >= dy {
+                err += dy;
+                pos.0 += sx;
+            }
+            if e2 <= dx {
+                err += dx;
+                pos.1 += sy;
+            }
+            line.push(pos);
+        }
+        line
+    }
+
+    // The diamond of points with a manhattan distance of at most `radius`, in reading order.
+    pub fn within_manhattan(&self, radius: i32) -> impl Iterator<Item = VecI2> {
+        let center = *self;
+        (-radius..=radius).flat_map(move |dy| {
+            let width = radius - dy.abs();
+            (-width..=width).map(move |dx| center + VecI2(dx, dy))
+        })
+    }
+}
@@ -0,0 +1,1100 @@
+#![allow(dead_code)]
+use std::collections::HashMap;
+use std::error::Error;
+use std::fmt::{Debug, Display};
+use std::fs;
+use std::io::{self, BufRead, BufReader, Lines};
+use std::iter::Copied;
+use std::mem;
+use std::num::TryFromIntError;
+use std::ops::{Add, Div, Mul, Neg, Rem, Sub};
+use std::slice;
+use std::str::{Chars, FromStr};
+use std::{fs::File, path::Path};
+
+use ndarray::{Array2, ArrayView2};
+
+pub mod bits;
+pub mod components;
+pub mod grid3d;
+pub mod hex;
+pub mod image;
+pub mod lattice;
+pub mod pattern;
+pub mod ray;
+pub mod region;
+pub mod render;
+pub mod search;
+pub mod sparse;
+pub mod torus;
+pub mod transform;
+
+pub fn read_strings(filename: &str) -> Vec<String> {
+    read_lines(filename)
+        .unwrap()
+        .map_while(io::Result::ok)
+        .collect()
+}
+
+// The output is wrapped in a Result to allow matching on errors
+// Returns an Iterator to the Reader of the lines of the file.
+fn read_lines<P>(filename: P) -> io::Result<Lines<BufReader<File>>>
+where
+    P: AsRef<Path>,
+{
+    let file = File::open(filename)?;
+    Ok(BufReader::new(file).lines())
+}
+
+pub struct PuzzleInput<S = String> {
+    pub input: S,
+    pub lines: Vec<S>,
+}
+
+pub type BorrowedPuzzleInput<'a> = PuzzleInput<&'a str>;
+
+impl PuzzleInput {
+    pub fn new(file_path: &str) -> Option<Self> {
+        let input = fs::read_to_string(file_path).unwrap_or_default();
+        if input.is_empty() {
+            return None;
+        }
+        let lines = input.lines().map(|s| s.to_string()).collect();
+        Some(Self { input, lines })
+    }
+
+    pub fn from_str(input: &str) -> Option<Self> {
+        let lines = input.lines().map(|s| s.to_string()).collect();
+        Some(Self {
+            input: input.to_string(),
+            lines,
+        })
+    }
+}
+
+impl<'a> PuzzleInput<&'a str> {
+    // Lines are slices into `input`, nothing is copied.
+    pub fn borrowed(input: &'a str) -> Self {
+        let lines = input.lines().collect();
+        Self { input, lines }
+    }
+}
+
+impl<S: AsRef<str>> PuzzleInput<S> {
+    pub fn as_borrowed(&self) -> BorrowedPuzzleInput<'_> {
+        PuzzleInput::borrowed(self.input.as_ref())
+    }
+
+    pub fn parsed<T>(&self) -> Vec<T>
+    where
+        T: FromStr,
+        <T as FromStr>::Err: Debug,
+    {
+        self.lines
+            .iter()
+            .map(|s| s.as_ref().parse::<T>().unwrap())
+            .collect()
+    }
+
+    pub fn parsed2d<T>(&self) -> Vec<Vec<T>>
+    where
+        T: FromStr,
+        <T as FromStr>::Err: Debug,
+    {
+        self.lines
+            .iter()
+            .map(|line| {
+                line.as_ref()
+                    .split_whitespace()
+                    .map(|s| s.parse::<T>().unwrap())
+                    .collect::<Vec<T>>()
+            })
+            .collect()
+    }
+
+    pub fn columns<T, const N: usize>(&self) -> [Vec<T>; N]
+    where
+        T: FromStr,
+        <T as FromStr>::Err: Debug,
+    {
+        self.split_columns(|line| line.split_whitespace().collect())
+    }
+
+    pub fn columns_delimited<T, const N: usize>(&self, delimiter: &str) -> [Vec<T>; N]
+    where
+        T: FromStr,
+        <T as FromStr>::Err: Debug,
+    {
+        self.split_columns(|line| line.split(delimiter).map(str::trim).collect())
+    }
+
+    fn split_columns<'a, T, const N: usize>(
+        &'a self,
+        split: impl Fn(&'a str) -> Vec<&'a str>,
+    ) -> [Vec<T>; N]
+    where
+        T: FromStr,
+        <T as FromStr>::Err: Debug,
+    {
+        let mut columns: [Vec<T>; N] =
+            std::array::from_fn(|_| Vec::with_capacity(self.lines.len()));
+        for (row, line) in self.lines.iter().map(S::as_ref).enumerate() {
+            let values = split(line);
+            if values.len() != N {
+                panic!(
+                    "Row {} has {} columns, expected {}: {:?}",
+                    row + 1,
+                    values.len(),
+                    N,
+                    line
+                );
+            }
+            for (column, value) in columns.iter_mut().zip(values) {
+                column.push(value.parse::<T>().unwrap());
+            }
+        }
+        columns
+    }
+
+    pub fn grid2d<T>(&self, f: fn(char) -> T) -> Grid2d<T> {
+        if self.input.as_ref().is_ascii() {
+            self.grid2d_bytes(|b| f(b as char))
+        } else {
+            Grid2d::from_lines(&self.lines, f)
+        }
+    }
+
+    pub fn try_grid2d<T>(&self, f: impl Fn(char) -> T) -> Result<Grid2d<T>, GridError> {
+        Grid2d::try_from_lines(&self.lines, f)
+    }
+
+    pub fn grid2d_padded<T: Clone>(&self, fill: T, f: impl Fn(char) -> T) -> Grid2d<T> {
+        Grid2d::from_lines_padded(&self.lines, fill, f)
+    }
+
+    pub fn grid2d_with_markers<T>(
+        &self,
+        markers: &[Marker],
+        tile: char,
+        f: impl Fn(char) -> T,
+    ) -> Result<(Grid2d<T>, Markers), GridError> {
+        Grid2d::from_lines_with_markers(&self.lines, markers, tile, f)
+    }
+
+    pub fn grid2d_bytes<T>(&self, f: impl Fn(u8) -> T) -> Grid2d<T> {
+        Grid2d::from_bytes(self.input.as_ref().as_bytes(), f)
+    }
+
+    pub fn digit_grid(&self) -> Grid2d<u8> {
+        self.grid2d_bytes(digit)
+    }
+
+    // All digits of the input in order, line breaks are skipped.
+    pub fn digits(&self) -> Vec<u8> {
+        self.input
+            .as_ref()
+            .bytes()
+            .filter(|b| !matches!(b, b'\n' | b'\r'))
+            .map(digit)
+            .collect()
+    }
+}
+
+pub fn digit(b: u8) -> u8 {
+    assert!(b.is_ascii_digit(), "Not a digit: {:?}", b as char);
+    b - b'0'
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Grid2d<T> {
+    pub width: usize,
+    pub height: usize,
+    pub vec: Vec<T>,
+}
+
+impl<T> Grid2d<T> {
+    pub fn new(width: usize, height: usize, vec: Vec<T>) -> Self {
+        Self { width, height, vec }
+    }
+
+    pub fn from_lines<S: AsRef<str>>(lines: &[S], f: impl Fn(char) -> T) -> Grid2d<T> {
+        Self::try_from_lines(lines, f).unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    pub fn try_from_lines<S: AsRef<str>>(
+        lines: &[S],
+        f: impl Fn(char) -> T,
+    ) -> Result<Grid2d<T>, GridError> {
+        Self::from_rows(line_rows(lines), f, None)
+    }
+
+    // Rows shorter than the longest one are filled up with `fill`.
+    pub fn from_lines_padded<S: AsRef<str>>(lines: &[S], fill: T, f: impl Fn(char) -> T) -> Self
+    where
+        T: Clone,
+    {
+        Self::from_rows(line_rows(lines), f, Some(&|| fill.clone())).unwrap()
+    }
+
+    // Builds the grid straight from the raw bytes without UTF-8 decoding or allocating per line.
+    pub fn from_bytes(bytes: &[u8], f: impl Fn(u8) -> T) -> Grid2d<T> {
+        Self::try_from_bytes(bytes, f).unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    pub fn try_from_bytes(bytes: &[u8], f: impl Fn(u8) -> T) -> Result<Grid2d<T>, GridError> {
+        Self::from_rows(byte_rows(bytes), f, None)
+    }
+
+    pub fn from_bytes_padded(bytes: &[u8], fill: T, f: impl Fn(u8) -> T) -> Self
+    where
+        T: Clone,
+    {
+        Self::from_rows(byte_rows(bytes), f, Some(&|| fill.clone())).unwrap()
+    }
+
+    // Marker cells are collected and replaced by `tile` before `f` is applied.
+    pub fn from_lines_with_markers<S: AsRef<str>>(
+        lines: &[S],
+        markers: &[Marker],
+        tile: char,
+        f: impl Fn(char) -> T,
+    ) -> Result<(Grid2d<T>, Markers), GridError> {
+        let chars = Grid2d::try_from_lines(lines, |c| c)?;
+        let mut found = Markers::default();
+        let mut vec = Vec::with_capacity(chars.vec.len());
+        for (pos, c) in chars.iter() {
+            if markers.iter().any(|marker| marker.symbol() == *c) {
+                found.positions.entry(*c).or_default().push(pos);
+                vec.push(f(tile));
+            } else {
+                vec.push(f(*c));
+            }
+        }
+        found.validate(markers)?;
+        Ok((Grid2d::new(chars.width, chars.height, vec), found))
+    }
+
+    fn from_rows<C, R>(
+        rows: impl Iterator<Item = R> + Clone,
+        f: impl Fn(C) -> T,
+        fill: Option<&dyn Fn() -> T>,
+    ) -> Result<Grid2d<T>, GridError>
+    where
+        R: Iterator<Item = C>,
+    {
+        let width = match fill {
+            Some(_) => rows.clone().map(|row| row.count()).max().unwrap_or(0),
+            None => rows.clone().next().map_or(0, |row| row.count()),
+        };
+
+        let mut height = 0;
+        let mut vec = Vec::new();
+        for (row, cells) in rows.enumerate() {
+            let start = vec.len();
+            vec.extend(cells.map(&f));
+            let actual = vec.len() - start;
+            if actual != width {
+                match fill {
+                    Some(fill) => vec.extend((actual..width).map(|_| fill())),
+                    None => {
+                        return Err(GridError::RaggedRow {
+                            row,
+                            expected: width,
+                            actual,
+                        })
+                    }
+                }
+            }
+            height += 1;
+        }
+        Ok(Grid2d::new(width, height, vec))
+    }
+
+    pub fn to_pos(&self, index: i32) -> Option<VecI2> {
+        if index < 0 || index >= self.width as i32 * self.height as i32 {
+            return None;
+        }
+        let x = index % self.width as i32;
+        let y = index / self.width as i32;
+        Some(VecI2(x, y))
+    }
+
+    pub fn in_bounds(&self, pos: &VecI2) -> bool {
+        pos.0 >= 0 && pos.1 >= 0 && pos.0 < self.width as i32 && pos.1 < self.height as i32
+    }
+
+    pub fn to_index(&self, pos: &VecI2) -> Option<usize> {
+        if !self.in_bounds(pos) {
+            return None;
+        }
+        Some(pos.0 as usize + pos.1 as usize * self.width)
+    }
+
+    pub fn get(&self, pos: &VecI2) -> Option<&T> {
+        let index = self.to_index(pos)?;
+        self.vec.get(index)
+    }
+
+    pub fn set(&mut self, pos: &VecI2, value: T) -> Option<()> {
+        let index = self.to_index(pos)?;
+        if let Some(a) = self.vec.get_mut(index) {
+            *a = value;
+            Some(())
+        } else {
+            None
+        }
+    }
+
+    pub fn map<U>(&self, f: impl Fn(&T) -> U) -> Grid2d<U> {
+        Grid2d::new(self.width, self.height, self.vec.iter().map(f).collect())
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (VecI2, &T)> {
+        self.vec
+            .iter()
+            .enumerate()
+            .map(|(i, t)| (VecI2((i % self.width) as i32, (i / self.width) as i32), t))
+    }
+
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = (VecI2, &mut T)> {
+        self.vec
+            .iter_mut()
+            .enumerate()
+            .map(|(i, t)| (VecI2((i % self.width) as i32, (i / self.width) as i32), t))
+    }
+
+    pub fn find_first(&self, f: impl Fn(&T) -> bool) -> Option<(VecI2, &T)> {
+        self.iter().find(|(_, t)| f(t))
+    }
+
+    pub fn get_mut(&mut self, pos: &VecI2) -> Option<&mut T> {
+        let index = self.to_index(pos)?;
+        self.vec.get_mut(index)
+    }
+
+    pub fn neighbors4(&self, pos: &VecI2) -> impl Iterator<Item = (VecI2, &T)> {
+        self.neighbors_at(NEIGHBORS4.map(|offset| *pos + offset))
+    }
+
+    pub fn neighbors8(&self, pos: &VecI2) -> impl Iterator<Item = (VecI2, &T)> {
+        self.neighbors_at(NEIGHBORS8.map(|offset| *pos + offset))
+    }
+
+    pub fn neighbors(
+        &self,
+        pos: &VecI2,
+        connectivity: Connectivity,
+    ) -> impl Iterator<Item = (VecI2, &T)> {
+        let pos = *pos;
+        self.neighbors_at(
+            connectivity
+                .offsets()
+                .iter()
+                .map(move |offset| pos + *offset),
+        )
+    }
+
+    pub fn neighbors_in<'a>(
+        &'a self,
+        pos: &VecI2,
+        directions: &'a [Direction],
+    ) -> impl Iterator<Item = (VecI2, &'a T)> {
+        let pos = *pos;
+        self.neighbors_at(directions.iter().map(move |d| pos.dir(d)))
+    }
+
+    fn neighbors_at(
+        &self,
+        positions: impl IntoIterator<Item = VecI2>,
+    ) -> impl Iterator<Item = (VecI2, &T)> {
+        positions
+            .into_iter()
+            .filter_map(|pos| self.get(&pos).map(|t| (pos, t)))
+    }
+
+    // The mutable variants yield the neighbors in reading order.
+    pub fn neighbors4_mut(&mut self, pos: &VecI2) -> impl Iterator<Item = (VecI2, &mut T)> {
+        self.neighbors_at_mut(&NEIGHBORS4.map(|offset| *pos + offset))
+    }
+
+    pub fn neighbors8_mut(&mut self, pos: &VecI2) -> impl Iterator<Item = (VecI2, &mut T)> {
+        self.neighbors_at_mut(&NEIGHBORS8.map(|offset| *pos + offset))
+    }
+
+    pub fn neighbors_in_mut(
+        &mut self,
+        pos: &VecI2,
+        directions: &[Direction],
+    ) -> impl Iterator<Item = (VecI2, &mut T)> {
+        self.neighbors_at_mut(&directions.iter().map(|d| pos.dir(d)).collect::<Vec<_>>())
+    }
+
+    fn neighbors_at_mut(&mut self, positions: &[VecI2]) -> impl Iterator<Item = (VecI2, &mut T)> {
+        let mut cells = positions
+            .iter()
+            .filter_map(|pos| self.to_index(pos).map(|index| (index, *pos)))
+            .collect::<Vec<_>>();
+        cells.sort_unstable_by_key(|(index, _)| *index);
+        cells.dedup_by_key(|(index, _)| *index);
+
+        // hand out disjoint borrows by splitting the remaining slice at each cell
+        let mut rest = self.vec.as_mut_slice();
+        let mut offset = 0;
+        cells.into_iter().map(move |(index, pos)| {
+            let (_, tail) = mem::take(&mut rest).split_at_mut(index - offset);
+            let (cell, tail) = tail.split_first_mut().unwrap();
+            rest = tail;
+            offset = index + 1;
+            (pos, cell)
+        })
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Connectivity {
+    Four,
+    Eight,
+}
+
+impl Connectivity {
+    pub fn offsets(&self) -> &'static [VecI2] {
+        match self {
+            Connectivity::Four => &NEIGHBORS4,
+            Connectivity::Eight => &NEIGHBORS8,
+        }
+    }
+}
+
+// Offsets in reading order
+const NEIGHBORS4: [VecI2; 4] = [VecI2(0, -1), VecI2(-1, 0), VecI2(1, 0), VecI2(0, 1)];
+const NEIGHBORS8: [VecI2; 8] = [
+    VecI2(-1, -1),
+    VecI2(0, -1),
+    VecI2(1, -1),
+    VecI2(-1, 0),
+    VecI2(1, 0),
+    VecI2(-1, 1),
+    VecI2(0, 1),
+    VecI2(1, 1),
+];
+
+// Rows of the grid are the first axis of the array.
+impl<T> Grid2d<T> {
+    pub fn array_view(&self) -> ArrayView2<'_, T> {
+        ArrayView2::from_shape((self.height, self.width), &self.vec).unwrap()
+    }
+
+    pub fn into_array(self) -> Array2<T> {
+        Array2::from_shape_vec((self.height, self.width), self.vec).unwrap()
+    }
+}
+
+impl<T> From<Array2<T>> for Grid2d<T> {
+    fn from(array: Array2<T>) -> Self {
+        let (height, width) = array.dim();
+        Grid2d::new(width, height, array.into_iter().collect())
+    }
+}
+
+impl<T: Display> Display for Grid2d<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.vec.iter().enumerate().for_each(|(i, t)| {
+            write!(f, "{t}").unwrap();
+            if i % self.width == self.width - 1 {
+                writeln!(f).unwrap();
+            }
+        });
+        Ok(())
+    }
+}
+
+// Trailing empty lines are ignored, like trailing line breaks in `byte_rows`.
+fn line_rows<S: AsRef<str>>(lines: &[S]) -> impl Iterator<Item = Chars<'_>> + Clone {
+    let end = lines
+        .iter()
+        .rposition(|line| !matches!(line.as_ref(), "" | "\r"))
+        .map_or(0, |i| i + 1);
+    lines[..end].iter().map(|line| {
+        let line = line.as_ref();
+        line.strip_suffix('\r').unwrap_or(line).chars()
+    })
+}
+
+// Trailing line breaks are ignored, an input without content has no rows at all.
+fn byte_rows(bytes: &[u8]) -> impl Iterator<Item = Copied<slice::Iter<'_, u8>>> + Clone {
+    let end = bytes
+        .iter()
+        .rposition(|b| !matches!(b, b'\n' | b'\r'))
+        .map_or(0, |i| i + 1);
+    bytes[..end]
+        .split(|b| *b == b'\n')
+        .take(if end == 0 { 0 } else { usize::MAX })
+        .map(|row| row.strip_suffix(b"\r").unwrap_or(row).iter().copied())
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Marker {
+    // Must appear exactly once.
+    One(char),
+    // May appear any number of times.
+    Many(char),
+}
+
+impl Marker {
+    pub fn symbol(&self) -> char {
+        match self {
+            Marker::One(c) | Marker::Many(c) => *c,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Markers {
+    positions: HashMap<char, Vec<VecI2>>,
+}
+
+impl Markers {
+    pub fn position(&self, symbol: char) -> Option<VecI2> {
+        self.positions(symbol).first().copied()
+    }
+
+    pub fn positions(&self, symbol: char) -> &[VecI2] {
+        self.positions.get(&symbol).map_or(&[], |p| p.as_slice())
+    }
+
+    fn validate(&self, markers: &[Marker]) -> Result<(), GridError> {
+        for marker in markers {
+            if let Marker::One(symbol) = *marker {
+                match self.positions(symbol) {
+                    [] => return Err(GridError::MissingMarker { symbol }),
+                    [_] => {}
+                    [first, second, ..] => {
+                        return Err(GridError::DuplicateMarker {
+                            symbol,
+                            first: *first,
+                            second: *second,
+                        })
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum GridError {
+    RaggedRow {
+        row: usize,
+        expected: usize,
+        actual: usize,
+    },
+    MissingMarker {
+        symbol: char,
+    },
+    DuplicateMarker {
+        symbol: char,
+        first: VecI2,
+        second: VecI2,
+    },
+}
+
+impl Display for GridError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            GridError::RaggedRow {
+                row,
+                expected,
+                actual,
+            } => write!(
+                f,
+                "Row {} has {} cells, expected {}",
+                row + 1,
+                actual,
+                expected
+            ),
+            GridError::MissingMarker { symbol } => write!(f, "Marker {:?} not found", symbol),
+            GridError::DuplicateMarker {
+                symbol,
+                first,
+                second,
+            } => write!(
+                f,
+                "Marker {:?} found more than once, at {:?} and {:?}",
+                symbol, first, second
+            ),
+        }
+    }
+}
+
+impl Error for GridError {}
+
+pub struct DisplayVec<T: Display>(pub Vec<T>);
+
+impl<T: Display> Display for DisplayVec<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}",
+            self.0
+                .iter()
+                .map(|e| e.to_string())
+                .collect::<Vec<String>>()
+                .join("")
+        )
+    }
+}
+
+pub trait Solution {
+    fn solve_one(&self, input: &BorrowedPuzzleInput) -> String;
+    fn test_input_one(&self) -> &str {
+        ""
+    }
+    fn expected_output_one(&self) -> &str {
+        ""
+    }
+
+    fn solve_two(&self, input: &BorrowedPuzzleInput) -> String;
+    fn test_input_two(&self) -> &str {
+        ""
+    }
+    fn expected_output_two(&self) -> &str {
+        ""
+    }
+
+    // Feeds the state of a step by step simulation to the sink, one frame per step. Returns
+    // false for days that aren't simulations.
+    fn simulate(&self, _input: &BorrowedPuzzleInput, _sink: &mut dyn FrameSink) -> bool {
+        false
+    }
+}
+
+pub trait FrameSink {
+    fn frame(&mut self, frame: &Grid2d<char>);
+}
+
+// A 2d vector over one integer type. VecI2 is used for grid positions, VecI64 for coordinates
+// that don't fit into an i32.
+macro_rules! vec2 {
+    ($name:ident, $t:ty) => {
+        #[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+        pub struct $name(pub $t, pub $t);
+
+        impl $name {
+            pub fn up(&self) -> Self {
+                Self(self.0, self.1 - 1)
+            }
+            pub fn right(&self) -> Self {
+                Self(self.0 + 1, self.1)
+            }
+            pub fn down(&self) -> Self {
+                Self(self.0, self.1 + 1)
+            }
+            pub fn left(&self) -> Self {
+                Self(self.0 - 1, self.1)
+            }
+
+            pub fn dir(&self, direction: &Direction) -> Self {
+                match direction {
+                    Direction::Left => self.left(),
+                    Direction::Right => self.right(),
+                    Direction::Up => self.up(),
+                    Direction::Down => self.down(),
+                }
+            }
+
+            pub fn min(&self, other: &Self) -> Self {
+                Self(self.0.min(other.0), self.1.min(other.1))
+            }
+
+            pub fn max(&self, other: &Self) -> Self {
+                Self(self.0.max(other.0), self.1.max(other.1))
+            }
+
+            pub fn dot(&self, other: &Self) -> $t {
+                self.0 * other.0 + self.1 * other.1
+            }
+
+            // The z component of the 3d cross product, positive if `other` is clockwise from
+            // `self` on screen.
+            pub fn cross(&self, other: &Self) -> $t {
+                self.0 * other.1 - self.1 * other.0
+            }
+
+            pub fn manhattan(&self, other: &Self) -> $t {
+                (self.0 - other.0).abs() + (self.1 - other.1).abs()
+            }
+
+            // The number of king moves.
+            pub fn chebyshev(&self, other: &Self) -> $t {
+                (self.0 - other.0).abs().max((self.1 - other.1).abs())
+            }
+
+            pub fn distance_squared(&self, other: &Self) -> $t {
+                let d = *self - *other;
+                d.dot(&d)
+            }
+
+            // The smallest step in the same direction that still lands on lattice points, (4, -6)
+            // becomes (2, -3). The zero vector stays zero. The gcd is taken of the unsigned
+            // magnitudes, so the minimum value doesn't overflow.
+            pub fn reduced(&self) -> Self {
+                let (mut a, mut b) = (self.0.unsigned_abs(), self.1.unsigned_abs());
+                while b != 0 {
+                    (a, b) = (b, a % b);
+                }
+                if a == 0 {
+                    return *self;
+                }
+                let reduce = |v: $t| {
+                    let magnitude = (v.unsigned_abs() / a) as $t;
+                    if v < 0 {
+                        magnitude.wrapping_neg()
+                    } else {
+                        magnitude
+                    }
+                };
+                Self(reduce(self.0), reduce(self.1))
+            }
+
+            // Component wise, the result is always in 0..modulus.
+            pub fn rem_euclid(&self, modulus: &Self) -> Self {
+                Self(self.0.rem_euclid(modulus.0), self.1.rem_euclid(modulus.1))
+            }
+        }
+
+        impl Add for $name {
+            type Output = Self;
+
+            fn add(self, rhs: Self) -> Self {
+                Self(self.0 + rhs.0, self.1 + rhs.1)
+            }
+        }
+
+        impl Sub for $name {
+            type Output = Self;
+
+            fn sub(self, rhs: Self) -> Self {
+                Self(self.0 - rhs.0, self.1 - rhs.1)
+            }
+        }
+
+        impl Neg for $name {
+            type Output = Self;
+
+            fn neg(self) -> Self {
+                Self(-self.0, -self.1)
+            }
+        }
+
+        impl Mul<$t> for $name {
+            type Output = Self;
+
+            fn mul(self, rhs: $t) -> Self {
+                Self(self.0 * rhs, self.1 * rhs)
+            }
+        }
+
+        impl Mul<$name> for $t {
+            type Output = $name;
+
+            fn mul(self, rhs: $name) -> $name {
+                rhs * self
+            }
+        }
+
+        impl Div<$t> for $name {
+            type Output = Self;
+
+            fn div(self, rhs: $t) -> Self {
+                Self(self.0 / rhs, self.1 / rhs)
+            }
+        }
+
+        // Component wise, with the sign of the dividend like the integer `%`.
+        impl Rem for $name {
+            type Output = Self;
+
+            fn rem(self, rhs: Self) -> Self {
+                Self(self.0 % rhs.0, self.1 % rhs.1)
+            }
+        }
+
+        impl Rem<$t> for $name {
+            type Output = Self;
+
+            fn rem(self, rhs: $t) -> Self {
+                Self(self.0 % rhs, self.1 % rhs)
+            }
+        }
+    };
+}
+
+vec2!(VecI2, i32);
+vec2!(VecI64, i64);
+
+impl From<VecI2> for VecI64 {
+    fn from(v: VecI2) -> Self {
+        Self(v.0 as i64, v.1 as i64)
+    }
+}
+
+impl TryFrom<VecI64> for VecI2 {
+    type Error = TryFromIntError;
+
+    fn try_from(v: VecI64) -> Result<Self, Self::Error> {
+        Ok(Self(v.0.try_into()?, v.1.try_into()?))
+    }
+}
+
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct VecI3(pub i32, pub i32, pub i32);
+
+impl VecI3 {
+    pub fn min(&self, other: &VecI3) -> VecI3 {
+        VecI3(
+            self.0.min(other.0),
+            self.1.min(other.1),
+            self.2.min(other.2),
+        )
+    }
+
+    pub fn max(&self, other: &VecI3) -> VecI3 {
+        VecI3(
+            self.0.max(other.0),
+            self.1.max(other.1),
+            self.2.max(other.2),
+        )
+    }
+}
+
+impl Add for VecI3 {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        Self(self.0 + rhs.0, self.1 + rhs.1, self.2 + rhs.2)
+    }
+}
+
+impl Sub for VecI3 {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self {
+        Self(self.0 - rhs.0, self.1 - rhs.1, self.2 - rhs.2)
+    }
+}
+
+impl Mul<i32> for VecI3 {
+    type Output = Self;
+
+    fn mul(self, rhs: i32) -> Self {
+        Self(self.0 * rhs, self.1 * rhs, self.2 * rhs)
+    }
+}
+
+impl Div<i32> for VecI3 {
+    type Output = Self;
+
+    fn div(self, rhs: i32) -> Self {
+        Self(self.0 / rhs, self.1 / rhs, self.2 / rhs)
+    }
+}
+
+// Faces, in reading order of the layers.
+pub const NEIGHBORS6: [VecI3; 6] = [
+    VecI3(0, 0, -1),
+    VecI3(0, -1, 0),
+    VecI3(-1, 0, 0),
+    VecI3(1, 0, 0),
+    VecI3(0, 1, 0),
+    VecI3(0, 0, 1),
+];
+
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum Direction {
+    Left,
+    Right,
+    Up,
+    Down,
+}
+
+impl Direction {
+    // Clockwise, starting up.
+    pub const ALL: [Direction; 4] = [
+        Direction::Up,
+        Direction::Right,
+        Direction::Down,
+        Direction::Left,
+    ];
+
+    // Arrows, as drawn on maps.
+    pub fn from_char(c: char) -> Option<Self> {
+        match c {
+            '<' | '←' => Some(Self::Left),
+            '>' | '→' => Some(Self::Right),
+            '^' | '↑' => Some(Self::Up),
+            'v' | '↓' => Some(Self::Down),
+            _ => None,
+        }
+    }
+
+    // "U", "D", "L" and "R" in either case.
+    pub fn parse_letter(c: char) -> Option<Self> {
+        match c.to_ascii_uppercase() {
+            'L' => Some(Self::Left),
+            'R' => Some(Self::Right),
+            'U' => Some(Self::Up),
+            'D' => Some(Self::Down),
+            _ => None,
+        }
+    }
+
+    // Compass letters, "N", "E", "S" and "W" in either case.
+    pub fn from_compass(c: char) -> Option<Self> {
+        match c.to_ascii_uppercase() {
+            'W' => Some(Self::Left),
+            'E' => Some(Self::Right),
+            'N' => Some(Self::Up),
+            'S' => Some(Self::Down),
+            _ => None,
+        }
+    }
+
+    pub fn turn_right(&self) -> Self {
+        match self {
+            Direction::Up => Direction::Right,
+            Direction::Right => Direction::Down,
+            Direction::Down => Direction::Left,
+            Direction::Left => Direction::Up,
+        }
+    }
+
+    pub fn turn_left(&self) -> Self {
+        self.turn_right().opposite()
+    }
+
+    pub fn opposite(&self) -> Self {
+        match self {
+            Direction::Up => Direction::Down,
+            Direction::Right => Direction::Left,
+            Direction::Down => Direction::Up,
+            Direction::Left => Direction::Right,
+        }
+    }
+
+    pub fn is_horizontal(&self) -> bool {
+        matches!(self, Direction::Left | Direction::Right)
+    }
+
+    pub fn is_vertical(&self) -> bool {
+        !self.is_horizontal()
+    }
+
+    pub fn to_vec(self) -> VecI2 {
+        VecI2(0, 0).dir(&self)
+    }
+}
+
+// Clockwise, starting up.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum Direction8 {
+    Up,
+    UpRight,
+    Right,
+    DownRight,
+    Down,
+    DownLeft,
+    Left,
+    UpLeft,
+}
+
+impl Direction8 {
+    pub const ALL: [Direction8; 8] = [
+        Direction8::Up,
+        Direction8::UpRight,
+        Direction8::Right,
+        Direction8::DownRight,
+        Direction8::Down,
+        Direction8::DownLeft,
+        Direction8::Left,
+        Direction8::UpLeft,
+    ];
+
+    pub fn offset(&self) -> VecI2 {
+        match self {
+            Direction8::Up => VecI2(0, -1),
+            Direction8::UpRight => VecI2(1, -1),
+            Direction8::Right => VecI2(1, 0),
+            Direction8::DownRight => VecI2(1, 1),
+            Direction8::Down => VecI2(0, 1),
+            Direction8::DownLeft => VecI2(-1, 1),
+            Direction8::Left => VecI2(-1, 0),
+            Direction8::UpLeft => VecI2(-1, -1),
+        }
+    }
+
+    // Both turn by 45 degrees.
+    pub fn turn_right(&self) -> Self {
+        Self::ALL[(*self as usize + 1) % 8]
+    }
+
+    pub fn turn_left(&self) -> Self {
+        Self::ALL[(*self as usize + 7) % 8]
+    }
+
+    pub fn opposite(&self) -> Self {
+        Self::ALL[(*self as usize + 4) % 8]
+    }
+
+    pub fn is_diagonal(&self) -> bool {
+        *self as usize % 2 == 1
+    }
+
+    // The straight direction, None for diagonals.
+    pub fn straight(&self) -> Option<Direction> {
+        match self {
+            Direction8::Up => Some(Direction::Up),
+            Direction8::Right => Some(Direction::Right),
+            Direction8::Down => Some(Direction::Down),
+            Direction8::Left => Some(Direction::Left),
+            _ => None,
+        }
+    }
+
+    // Compass names, "N", "NE", "E", ... in either case.
+    pub fn parse(s: &str) -> Option<Self> {
+        match s.to_ascii_uppercase().as_str() {
+            "N" => Some(Direction8::Up),
+            "NE" => Some(Direction8::UpRight),
+            "E" => Some(Direction8::Right),
+            "SE" => Some(Direction8::DownRight),
+            "S" => Some(Direction8::Down),
+            "SW" => Some(Direction8::DownLeft),
+            "W" => Some(Direction8::Left),
+            "NW" => Some(Direction8::UpLeft),
+            _ => None,
+        }
+    }
+}
+
+impl From<Direction> for Direction8 {
+    fn from(direction: Direction) -> Self {
+        match direction {
+            Direction::Up => Direction8::Up,
+            Direction::Right => Direction8::Right,
+            Direction::Down => Direction8::Down,
+            Direction::Left => Direction8::Left,
+        }
+    }
+}
+
+impl Display for Direction {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Direction::Left => write!(f, "<"),
+            Direction::Right => write!(f, ">"),
+            Direction::Up => write!(f, "^"),
+            Direction::Down => write!(f, "v"),
+        }
+    }
+}
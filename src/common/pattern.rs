@@ -0,0 +1,106 @@
+use super::{
+    transform::{GridView, Orientation, Symmetry},
+    Grid2d, VecI2,
+};
+
+// `pos` is the top left corner of the transformed pattern.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct PatternMatch {
+    pub pos: VecI2,
+    pub orientation: Orientation,
+}
+
+// The fixed cells of a transformed pattern as offsets into the grid's cell vector.
+struct CompiledPattern<'a, T> {
+    orientation: Orientation,
+    width: usize,
+    height: usize,
+    cells: Vec<(usize, &'a T)>,
+}
+
+// A pattern is a grid where `None` cells match anything.
+impl<T> Grid2d<Option<T>> {
+    pub fn pattern_from_lines<S: AsRef<str>>(
+        lines: &[S],
+        wildcard: char,
+        f: impl Fn(char) -> T,
+    ) -> Self {
+        Self::from_lines(lines, |c| if c == wildcard { None } else { Some(f(c)) })
+    }
+}
+
+impl<T: PartialEq> Grid2d<T> {
+    // Whether the pattern matches with its top left corner at `pos`.
+    pub fn matches_at(&self, pos: &VecI2, pattern: &Grid2d<Option<T>>) -> bool {
+        self.view()
+            .window(pos, pattern.width, pattern.height)
+            .matches(pattern)
+    }
+}
+
+impl<T: PartialEq + Clone> Grid2d<T> {
+    // All occurrences of the pattern in any orientation of the symmetry, in reading order.
+    // Orientations that turn the pattern into one already searched for are skipped, so every
+    // occurrence is reported once with the first orientation that produces it.
+    pub fn find_pattern(
+        &self,
+        pattern: &Grid2d<Option<T>>,
+        symmetry: Symmetry,
+    ) -> Vec<PatternMatch> {
+        let mut oriented: Vec<(Orientation, Grid2d<Option<T>>)> = vec![];
+        for orientation in symmetry.orientations() {
+            let grid = orientation.apply(pattern.view()).to_grid();
+            if oriented.iter().all(|(_, other)| *other != grid) {
+                oriented.push((*orientation, grid));
+            }
+        }
+        let compiled = oriented
+            .iter()
+            .map(|(orientation, pattern)| CompiledPattern {
+                orientation: *orientation,
+                width: pattern.width,
+                height: pattern.height,
+                cells: pattern
+                    .iter()
+                    .filter_map(|(pos, t)| {
+                        let offset = pos.1 as usize * self.width + pos.0 as usize;
+                        t.as_ref().map(|t| (offset, t))
+                    })
+                    .collect(),
+            })
+            .collect::<Vec<_>>();
+
+        let mut matches = vec![];
+        for y in 0..self.height {
+            for x in 0..self.width {
+                let start = y * self.width + x;
+                for pattern in &compiled {
+                    if x + pattern.width <= self.width
+                        && y + pattern.height <= self.height
+                        && pattern
+                            .cells
+                            .iter()
+                            .all(|(offset, t)| self.vec[start + offset] == **t)
+                    {
+                        matches.push(PatternMatch {
+                            pos: VecI2(x as i32, y as i32),
+                            orientation: pattern.orientation,
+                        });
+                    }
+                }
+            }
+        }
+        matches
+    }
+}
+
+impl<T: PartialEq> GridView<'_, T> {
+    pub fn matches(&self, pattern: &Grid2d<Option<T>>) -> bool {
+        self.width == pattern.width
+            && self.height == pattern.height
+            && pattern.iter().all(|(pos, expected)| match expected {
+                None => true,
+                Some(expected) => self.get(&pos) == Some(expected),
+            })
+    }
+}
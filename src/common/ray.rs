@@ -0,0 +1,59 @@
+use super::{Direction8, Grid2d, VecI2};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WordMatch {
+    pub start: VecI2,
+    pub direction: Direction8,
+    pub cells: Vec<VecI2>,
+}
+
+impl<T> Grid2d<T> {
+    // The cells from `start` in `direction`, starting with `start` itself, until the edge of the
+    // grid. Use `take` to walk a fixed number of steps.
+    pub fn ray(
+        &self,
+        start: &VecI2,
+        direction: Direction8,
+    ) -> impl Iterator<Item = (VecI2, &T)> + '_ {
+        let offset = direction.offset();
+        let mut pos = *start;
+        std::iter::from_fn(move || {
+            let t = self.get(&pos)?;
+            let current = pos;
+            pos = pos + offset;
+            Some((current, t))
+        })
+    }
+}
+
+impl<T: PartialEq> Grid2d<T> {
+    // Every occurrence of the word along a straight line, ordered by start in reading order and
+    // then by direction. Palindromes are found once in each direction.
+    pub fn find_word(&self, word: &[T]) -> Vec<WordMatch> {
+        let mut matches = vec![];
+        let Some(first) = word.first() else {
+            return matches;
+        };
+        for (start, t) in self.iter() {
+            if t != first {
+                continue;
+            }
+            for direction in Direction8::ALL {
+                let cells = self
+                    .ray(&start, direction)
+                    .zip(word)
+                    .take_while(|((_, t), expected)| t == expected)
+                    .map(|((pos, _), _)| pos)
+                    .collect::<Vec<_>>();
+                if cells.len() == word.len() {
+                    matches.push(WordMatch {
+                        start,
+                        direction,
+                        cells,
+                    });
+                }
+            }
+        }
+        matches
+    }
+}
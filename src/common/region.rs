@@ -0,0 +1,232 @@
+use rustc_hash::{FxHashMap, FxHashSet};
+
+use crate::geometry::{Bounded2, Bounds2};
+
+use super::{VecI2, NEIGHBORS4, NEIGHBORS8};
+
+// An arbitrary set of cells. Cells are connected through their edges, so cells that only touch
+// at a corner are treated as separate pieces and the empty cells around such a corner as
+// connected.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct Region {
+    cells: FxHashSet<VecI2>,
+}
+
+// A closed boundary as lattice points on the cell corners, cell (x, y) spans (x, y) to
+// (x + 1, y + 1). Only the points where the boundary changes direction are included. Outer
+// boundaries run clockwise (on screen, y pointing down), boundaries of holes counterclockwise.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Outline {
+    pub vertices: Vec<VecI2>,
+    pub is_hole: bool,
+}
+
+impl Region {
+    pub fn new(cells: impl IntoIterator<Item = VecI2>) -> Self {
+        Self {
+            cells: cells.into_iter().collect(),
+        }
+    }
+
+    pub fn area(&self) -> usize {
+        self.cells.len()
+    }
+
+    pub fn contains(&self, pos: &VecI2) -> bool {
+        self.cells.contains(pos)
+    }
+
+    pub fn cells(&self) -> impl Iterator<Item = &VecI2> {
+        self.cells.iter()
+    }
+
+    pub fn bounds(&self) -> Option<Bounds2> {
+        Bounds2::from_points(self.cells.iter().copied())
+    }
+
+    pub fn perimeter(&self) -> usize {
+        self.cells
+            .iter()
+            .map(|pos| {
+                NEIGHBORS4
+                    .iter()
+                    .filter(|offset| !self.contains(&(*pos + **offset)))
+                    .count()
+            })
+            .sum()
+    }
+
+    // Every corner starts a new straight side, so both numbers are the same.
+    pub fn corners(&self) -> usize {
+        let diagonals = [VecI2(-1, -1), VecI2(1, -1), VecI2(1, 1), VecI2(-1, 1)];
+        self.cells
+            .iter()
+            .map(|pos| {
+                diagonals
+                    .iter()
+                    .filter(|d| {
+                        let horizontal = self.contains(&VecI2(pos.0 + d.0, pos.1));
+                        let vertical = self.contains(&VecI2(pos.0, pos.1 + d.1));
+                        let diagonal = self.contains(&(*pos + **d));
+                        let convex = !horizontal && !vertical;
+                        let concave = horizontal && vertical && !diagonal;
+                        convex || concave
+                    })
+                    .count()
+            })
+            .sum()
+    }
+
+    pub fn sides(&self) -> usize {
+        self.corners()
+    }
+
+    // Groups of empty cells that are completely enclosed by the region.
+    pub fn holes(&self) -> Vec<Region> {
+        let Some(bounds) = self.bounds() else {
+            return vec![];
+        };
+        let min = bounds.min() - VecI2(1, 1);
+        let max = bounds.max() + VecI2(1, 1);
+        let inside =
+            |pos: &VecI2| pos.0 >= min.0 && pos.1 >= min.1 && pos.0 <= max.0 && pos.1 <= max.1;
+
+        let mut empty = FxHashSet::default();
+        for y in min.1..=max.1 {
+            for x in min.0..=max.0 {
+                if !self.contains(&VecI2(x, y)) {
+                    empty.insert(VecI2(x, y));
+                }
+            }
+        }
+
+        // the padding row above the bounds is always outside
+        let mut holes = vec![];
+        let mut outside = true;
+        while let Some(start) = empty.iter().min_by_key(|pos| (pos.1, pos.0)).copied() {
+            empty.remove(&start);
+            let mut cells = vec![start];
+            let mut stack = vec![start];
+            while let Some(pos) = stack.pop() {
+                for offset in NEIGHBORS8 {
+                    let next = pos + offset;
+                    if inside(&next) && empty.remove(&next) {
+                        cells.push(next);
+                        stack.push(next);
+                    }
+                }
+            }
+            if !outside {
+                holes.push(Region::new(cells));
+            }
+            outside = false;
+        }
+        holes
+    }
+
+    pub fn outlines(&self) -> Vec<Outline> {
+        // directed boundary edges with the region on their right side
+        let mut edges: FxHashMap<VecI2, Vec<VecI2>> = FxHashMap::default();
+        for pos in &self.cells {
+            let VecI2(x, y) = *pos;
+            let sides = [
+                (VecI2(0, -1), VecI2(x, y), VecI2(1, 0)),
+                (VecI2(1, 0), VecI2(x + 1, y), VecI2(0, 1)),
+                (VecI2(0, 1), VecI2(x + 1, y + 1), VecI2(-1, 0)),
+                (VecI2(-1, 0), VecI2(x, y + 1), VecI2(0, -1)),
+            ];
+            for (offset, start, direction) in sides {
+                if !self.contains(&(*pos + offset)) {
+                    edges.entry(start).or_default().push(direction);
+                }
+            }
+        }
+
+        let mut outlines = vec![];
+        while let Some(first) = edges.keys().min_by_key(|v| (v.1, v.0)).copied() {
+            let first_direction = edges[&first][0];
+            let mut vertices = vec![];
+            let mut pos = first;
+            let mut direction = first_direction;
+            loop {
+                remove_edge(&mut edges, &pos, &direction);
+                let next = pos + direction;
+                // turning right keeps cells that only touch at a corner apart
+                let right = VecI2(-direction.1, direction.0);
+                let left = VecI2(direction.1, -direction.0);
+                let next_direction = [right, direction, left]
+                    .into_iter()
+                    .find(|d| {
+                        (next == first && *d == first_direction)
+                            || edges.get(&next).is_some_and(|e| e.contains(d))
+                    })
+                    .unwrap();
+                if next_direction != direction {
+                    vertices.push(next);
+                }
+                if next == first && next_direction == first_direction {
+                    break;
+                }
+                pos = next;
+                direction = next_direction;
+            }
+            vertices.rotate_right(1);
+
+            let is_hole = signed_area(&vertices) < 0;
+            outlines.push(Outline { vertices, is_hole });
+        }
+        outlines
+    }
+}
+
+fn remove_edge(edges: &mut FxHashMap<VecI2, Vec<VecI2>>, start: &VecI2, direction: &VecI2) {
+    let directions = edges.get_mut(start).unwrap();
+    directions.retain(|d| d != direction);
+    if directions.is_empty() {
+        edges.remove(start);
+    }
+}
+
+// Twice the area enclosed by the polygon, positive for clockwise polygons.
+fn signed_area(vertices: &[VecI2]) -> i64 {
+    vertices
+        .iter()
+        .zip(vertices.iter().cycle().skip(1))
+        .map(|(a, b)| a.0 as i64 * b.1 as i64 - b.0 as i64 * a.1 as i64)
+        .sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::common::Grid2d;
+
+    fn region(lines: &[&str]) -> Region {
+        let grid = Grid2d::from_lines(lines, |c| c == '#');
+        Region::new(grid.iter().filter(|(_, t)| **t).map(|(pos, _)| pos))
+    }
+
+    #[test]
+    fn ring_has_one_hole() {
+        let ring = region(&["###", "#.#", "###"]);
+        assert_eq!((ring.area(), ring.perimeter(), ring.sides()), (8, 16, 8));
+
+        let holes = ring.holes();
+        assert_eq!(holes.len(), 1);
+        assert!(holes[0].contains(&VecI2(1, 1)));
+
+        let outlines = ring.outlines();
+        assert_eq!(outlines.len(), 2);
+        let (holes, outer): (Vec<_>, Vec<_>) = outlines.iter().partition(|o| o.is_hole);
+        assert_eq!(holes.len(), 1);
+        assert_eq!(outer[0].vertices.len(), 4);
+        assert_eq!(holes[0].vertices.len(), 4);
+    }
+
+    #[test]
+    fn diagonal_gap_is_not_a_hole() {
+        let region = region(&[".##", "#.#", "##."]);
+        assert!(region.holes().is_empty());
+        assert!(region.outlines().iter().all(|o| !o.is_hole));
+    }
+}
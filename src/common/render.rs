@@ -0,0 +1,169 @@
+use std::{
+    env,
+    fmt::{Display, Write},
+    io::{self, IsTerminal},
+};
+
+use rustc_hash::FxHashSet;
+
+use super::{Grid2d, VecI2};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Color {
+    Black,
+    Red,
+    Green,
+    Yellow,
+    Blue,
+    Magenta,
+    Cyan,
+    White,
+    Gray,
+    Rgb(u8, u8, u8),
+}
+
+impl Color {
+    fn ansi(&self) -> String {
+        match self {
+            Color::Black => "\x1b[30m".to_string(),
+            Color::Red => "\x1b[31m".to_string(),
+            Color::Green => "\x1b[32m".to_string(),
+            Color::Yellow => "\x1b[33m".to_string(),
+            Color::Blue => "\x1b[34m".to_string(),
+            Color::Magenta => "\x1b[35m".to_string(),
+            Color::Cyan => "\x1b[36m".to_string(),
+            Color::White => "\x1b[37m".to_string(),
+            Color::Gray => "\x1b[90m".to_string(),
+            Color::Rgb(r, g, b) => format!("\x1b[38;2;{};{};{}m", r, g, b),
+        }
+    }
+}
+
+const RESET: &str = "\x1b[0m";
+
+// Positions to highlight. Without a glyph the cell keeps its own character.
+#[derive(Debug, Clone)]
+pub struct Overlay {
+    pub positions: FxHashSet<VecI2>,
+    pub glyph: Option<char>,
+    pub color: Color,
+}
+
+impl Overlay {
+    pub fn new(
+        positions: impl IntoIterator<Item = VecI2>,
+        glyph: Option<char>,
+        color: Color,
+    ) -> Self {
+        Self {
+            positions: positions.into_iter().collect(),
+            glyph,
+            color,
+        }
+    }
+}
+
+type CellColor<'a, T> = Box<dyn Fn(&T) -> Option<Color> + 'a>;
+
+// Draws a grid with overlays on top, later overlays win. Colors are only used when enabled, by
+// default that's when stdout is a terminal and NO_COLOR isn't set. Without colors only the
+// glyphs of the overlays show.
+pub struct Renderer<'a, T> {
+    grid: &'a Grid2d<T>,
+    base_color: Option<CellColor<'a, T>>,
+    overlays: Vec<Overlay>,
+    color: bool,
+}
+
+pub fn color_supported() -> bool {
+    io::stdout().is_terminal() && env::var_os("NO_COLOR").is_none_or(|v| v.is_empty())
+}
+
+impl<'a, T: Display> Renderer<'a, T> {
+    pub fn new(grid: &'a Grid2d<T>) -> Self {
+        Self {
+            grid,
+            base_color: None,
+            overlays: vec![],
+            color: color_supported(),
+        }
+    }
+
+    // Colors for the grid's own cells.
+    pub fn base_color(self, color: impl Fn(&T) -> Option<Color> + 'a) -> Self {
+        Self {
+            base_color: Some(Box::new(color)),
+            ..self
+        }
+    }
+
+    pub fn overlay(mut self, overlay: Overlay) -> Self {
+        self.overlays.push(overlay);
+        self
+    }
+
+    pub fn highlight(
+        self,
+        positions: impl IntoIterator<Item = VecI2>,
+        glyph: Option<char>,
+        color: Color,
+    ) -> Self {
+        self.overlay(Overlay::new(positions, glyph, color))
+    }
+
+    pub fn color(self, enabled: bool) -> Self {
+        Self {
+            color: enabled,
+            ..self
+        }
+    }
+
+    fn cell(&self, pos: &VecI2, t: &T) -> (String, Option<Color>) {
+        let overlay = self
+            .overlays
+            .iter()
+            .rev()
+            .find(|overlay| overlay.positions.contains(pos));
+        match overlay {
+            Some(overlay) => (
+                overlay.glyph.map_or_else(|| t.to_string(), String::from),
+                Some(overlay.color),
+            ),
+            None => (
+                t.to_string(),
+                self.base_color.as_ref().and_then(|color| color(t)),
+            ),
+        }
+    }
+}
+
+impl<T: Display> Grid2d<T> {
+    pub fn renderer(&self) -> Renderer<'_, T> {
+        Renderer::new(self)
+    }
+}
+
+impl<T: Display> Display for Renderer<'_, T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for y in 0..self.grid.height as i32 {
+            let mut current = None;
+            for x in 0..self.grid.width as i32 {
+                let pos = VecI2(x, y);
+                let (text, color) = self.cell(&pos, self.grid.get(&pos).unwrap());
+                if self.color && color != current {
+                    match color {
+                        Some(color) => f.write_str(&color.ansi())?,
+                        None => f.write_str(RESET)?,
+                    }
+                    current = color;
+                }
+                f.write_str(&text)?;
+            }
+            if current.is_some() {
+                f.write_str(RESET)?;
+            }
+            f.write_char('\n')?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,384 @@
+use std::{
+    cmp::Ordering,
+    collections::{BinaryHeap, VecDeque},
+    hash::Hash,
+    ops::Add,
+};
+
+use rustc_hash::{FxHashMap, FxHashSet};
+
+use super::{Grid2d, VecI2};
+
+pub trait Cost: Copy + Ord + Default + Add<Output = Self> {}
+
+impl<C: Copy + Ord + Default + Add<Output = C>> Cost for C {}
+
+// The shortest path DAG found by a search: the cost of every reached node and all of its
+// predecessors on a shortest path.
+#[derive(Debug, Clone)]
+pub struct ShortestPaths<N, C> {
+    start: N,
+    nodes: FxHashMap<N, (C, Vec<N>)>,
+}
+
+impl<N, C> ShortestPaths<N, C>
+where
+    N: Clone + Eq + Hash,
+    C: Cost,
+{
+    fn new(start: N) -> Self {
+        let mut nodes = FxHashMap::default();
+        nodes.insert(start.clone(), (C::default(), vec![]));
+        Self { start, nodes }
+    }
+
+    pub fn start(&self) -> &N {
+        &self.start
+    }
+
+    pub fn cost(&self, node: &N) -> Option<C> {
+        self.nodes.get(node).map(|(cost, _)| *cost)
+    }
+
+    pub fn contains(&self, node: &N) -> bool {
+        self.nodes.contains_key(node)
+    }
+
+    pub fn nodes(&self) -> impl Iterator<Item = (&N, C)> {
+        self.nodes.iter().map(|(node, (cost, _))| (node, *cost))
+    }
+
+    pub fn parents(&self, node: &N) -> &[N] {
+        self.nodes.get(node).map_or(&[], |(_, parents)| parents)
+    }
+
+    // One of the shortest paths, including start and target.
+    pub fn path_to(&self, target: &N) -> Option<Vec<N>> {
+        if !self.contains(target) {
+            return None;
+        }
+        let mut path = vec![target.clone()];
+        while let Some(parent) = self.parents(path.last().unwrap()).first() {
+            path.push(parent.clone());
+        }
+        path.reverse();
+        Some(path)
+    }
+
+    pub fn paths_to(&self, target: &N) -> Vec<Vec<N>> {
+        if !self.contains(target) {
+            return vec![];
+        }
+        let parents = self.parents(target);
+        if parents.is_empty() {
+            return vec![vec![target.clone()]];
+        }
+        parents
+            .iter()
+            .flat_map(|parent| self.paths_to(parent))
+            .map(|mut path| {
+                path.push(target.clone());
+                path
+            })
+            .collect()
+    }
+
+    pub fn count_paths_to(&self, target: &N) -> usize {
+        let mut counts = FxHashMap::default();
+        self.count_paths(target, &mut counts)
+    }
+
+    fn count_paths(&self, node: &N, counts: &mut FxHashMap<N, usize>) -> usize {
+        if let Some(count) = counts.get(node) {
+            return *count;
+        }
+        let count = match self.nodes.get(node) {
+            None => 0,
+            Some((_, parents)) if parents.is_empty() => 1,
+            Some((_, parents)) => parents.iter().map(|p| self.count_paths(p, counts)).sum(),
+        };
+        counts.insert(node.clone(), count);
+        count
+    }
+
+    // Every node that lies on at least one shortest path to one of the targets.
+    pub fn nodes_on_paths_to(&self, targets: &[N]) -> FxHashSet<N> {
+        let mut visited = FxHashSet::default();
+        let mut stack = targets
+            .iter()
+            .filter(|t| self.contains(t))
+            .cloned()
+            .collect::<Vec<_>>();
+        while let Some(node) = stack.pop() {
+            if visited.insert(node.clone()) {
+                stack.extend(self.parents(&node).iter().cloned());
+            }
+        }
+        visited
+    }
+
+    fn relax(&mut self, node: &N, parent: &N, cost: C) -> bool {
+        match self.nodes.get_mut(node) {
+            Some((old_cost, parents)) => match cost.cmp(old_cost) {
+                Ordering::Less => {
+                    *old_cost = cost;
+                    *parents = vec![parent.clone()];
+                    true
+                }
+                Ordering::Equal => {
+                    if !parents.contains(parent) {
+                        parents.push(parent.clone());
+                    }
+                    false
+                }
+                Ordering::Greater => false,
+            },
+            None => {
+                self.nodes
+                    .insert(node.clone(), (cost, vec![parent.clone()]));
+                true
+            }
+        }
+    }
+}
+
+// Distances and the shortest path DAG of every node reachable from `start`.
+pub fn bfs<N, IN>(start: N, mut successors: impl FnMut(&N) -> IN) -> ShortestPaths<N, usize>
+where
+    N: Clone + Eq + Hash,
+    IN: IntoIterator<Item = N>,
+{
+    let mut paths = ShortestPaths::new(start.clone());
+    let mut queue = VecDeque::from([start]);
+    while let Some(node) = queue.pop_front() {
+        let cost = paths.cost(&node).unwrap() + 1;
+        for next in successors(&node) {
+            if paths.relax(&next, &node, cost) {
+                queue.push_back(next);
+            }
+        }
+    }
+    paths
+}
+
+pub fn bfs_path<N, IN>(
+    start: N,
+    mut successors: impl FnMut(&N) -> IN,
+    mut success: impl FnMut(&N) -> bool,
+) -> Option<Vec<N>>
+where
+    N: Clone + Eq + Hash,
+    IN: IntoIterator<Item = N>,
+{
+    let mut parents: FxHashMap<N, Option<N>> = FxHashMap::default();
+    parents.insert(start.clone(), None);
+    let mut queue = VecDeque::from([start]);
+    while let Some(node) = queue.pop_front() {
+        if success(&node) {
+            let mut path = vec![node];
+            while let Some(Some(parent)) = parents.get(path.last().unwrap()) {
+                path.push(parent.clone());
+            }
+            path.reverse();
+            return Some(path);
+        }
+        for next in successors(&node) {
+            if !parents.contains_key(&next) {
+                parents.insert(next.clone(), Some(node.clone()));
+                queue.push_back(next);
+            }
+        }
+    }
+    None
+}
+
+pub fn dijkstra<N, C, IN>(
+    start: N,
+    successors: impl FnMut(&N) -> IN,
+    success: impl FnMut(&N) -> bool,
+) -> Option<(Vec<N>, C)>
+where
+    N: Clone + Eq + Hash,
+    C: Cost,
+    IN: IntoIterator<Item = (N, C)>,
+{
+    astar(start, successors, |_| C::default(), success)
+}
+
+// Explores the whole reachable graph.
+pub fn dijkstra_all<N, C, IN>(start: N, successors: impl FnMut(&N) -> IN) -> ShortestPaths<N, C>
+where
+    N: Clone + Eq + Hash,
+    C: Cost,
+    IN: IntoIterator<Item = (N, C)>,
+{
+    search(start, successors, |_| C::default(), |_| false).0
+}
+
+pub fn astar<N, C, IN>(
+    start: N,
+    successors: impl FnMut(&N) -> IN,
+    heuristic: impl FnMut(&N) -> C,
+    success: impl FnMut(&N) -> bool,
+) -> Option<(Vec<N>, C)>
+where
+    N: Clone + Eq + Hash,
+    C: Cost,
+    IN: IntoIterator<Item = (N, C)>,
+{
+    let (paths, targets) = astar_bag(start, successors, heuristic, success)?;
+    let target = &targets[0];
+    Some((paths.path_to(target).unwrap(), paths.cost(target).unwrap()))
+}
+
+// All shortest paths to the cheapest targets. The targets reached at that cost are returned
+// alongside the paths.
+pub fn astar_bag<N, C, IN>(
+    start: N,
+    successors: impl FnMut(&N) -> IN,
+    heuristic: impl FnMut(&N) -> C,
+    success: impl FnMut(&N) -> bool,
+) -> Option<(ShortestPaths<N, C>, Vec<N>)>
+where
+    N: Clone + Eq + Hash,
+    C: Cost,
+    IN: IntoIterator<Item = (N, C)>,
+{
+    let (paths, targets) = search(start, successors, heuristic, success);
+    if targets.is_empty() {
+        None
+    } else {
+        Some((paths, targets))
+    }
+}
+
+struct State<N, C> {
+    estimate: C,
+    cost: C,
+    node: N,
+}
+
+impl<N, C: Ord> PartialEq for State<N, C> {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl<N, C: Ord> Eq for State<N, C> {}
+
+impl<N, C: Ord> PartialOrd for State<N, C> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<N, C: Ord> Ord for State<N, C> {
+    // lowest estimate first, on ties prefer the node that got further
+    fn cmp(&self, other: &Self) -> Ordering {
+        other
+            .estimate
+            .cmp(&self.estimate)
+            .then_with(|| self.cost.cmp(&other.cost))
+    }
+}
+
+fn search<N, C, IN>(
+    start: N,
+    mut successors: impl FnMut(&N) -> IN,
+    mut heuristic: impl FnMut(&N) -> C,
+    mut success: impl FnMut(&N) -> bool,
+) -> (ShortestPaths<N, C>, Vec<N>)
+where
+    N: Clone + Eq + Hash,
+    C: Cost,
+    IN: IntoIterator<Item = (N, C)>,
+{
+    let mut paths = ShortestPaths::new(start.clone());
+    let mut targets = vec![];
+    let mut best = None;
+    let mut heap = BinaryHeap::from([State {
+        estimate: heuristic(&start),
+        cost: C::default(),
+        node: start,
+    }]);
+
+    while let Some(State {
+        estimate,
+        cost,
+        node,
+    }) = heap.pop()
+    {
+        if best.is_some_and(|best| estimate > best) {
+            break;
+        }
+        if paths.cost(&node).is_some_and(|c| cost > c) {
+            continue;
+        }
+        if success(&node) {
+            best = Some(cost);
+            if !targets.contains(&node) {
+                targets.push(node);
+            }
+            continue;
+        }
+        for (next, step) in successors(&node) {
+            let next_cost = cost + step;
+            if paths.relax(&next, &node, next_cost) {
+                heap.push(State {
+                    estimate: next_cost + heuristic(&next),
+                    cost: next_cost,
+                    node: next,
+                });
+            }
+        }
+    }
+    (paths, targets)
+}
+
+impl<T> Grid2d<T> {
+    pub fn passable_neighbors4<'a>(
+        &'a self,
+        pos: &VecI2,
+        passable: impl Fn(&T) -> bool + 'a,
+    ) -> impl Iterator<Item = VecI2> + 'a {
+        self.neighbors4(pos)
+            .filter(move |(_, t)| passable(t))
+            .map(|(p, _)| p)
+    }
+
+    // Number of steps from `start` to every reachable cell.
+    pub fn bfs_distances(
+        &self,
+        start: &VecI2,
+        passable: impl Fn(&T) -> bool,
+    ) -> Grid2d<Option<usize>> {
+        let mut distances = Grid2d::new(self.width, self.height, vec![None; self.vec.len()]);
+        if distances.set(start, Some(0)).is_none() {
+            return distances;
+        }
+        let mut queue = VecDeque::from([(*start, 0)]);
+        while let Some((pos, distance)) = queue.pop_front() {
+            for next in self.passable_neighbors4(&pos, &passable) {
+                let cell = distances.get_mut(&next).unwrap();
+                if cell.is_none() {
+                    *cell = Some(distance + 1);
+                    queue.push_back((next, distance + 1));
+                }
+            }
+        }
+        distances
+    }
+
+    pub fn shortest_path(
+        &self,
+        start: &VecI2,
+        goal: &VecI2,
+        passable: impl Fn(&T) -> bool,
+    ) -> Option<Vec<VecI2>> {
+        bfs_path(
+            *start,
+            |pos| self.passable_neighbors4(pos, &passable),
+            |pos| pos == goal,
+        )
+    }
+}
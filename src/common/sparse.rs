@@ -0,0 +1,155 @@
+use std::fmt::Display;
+
+use rustc_hash::FxHashMap;
+
+use crate::geometry::{Bounded2, Bounds2, Extendable};
+
+use super::{Grid2d, VecI2, NEIGHBORS4, NEIGHBORS8};
+
+// An unbounded grid that only stores the occupied cells. Coordinates may be negative, the
+// bounds grow and shrink with the contents.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SparseGrid<T> {
+    cells: FxHashMap<VecI2, T>,
+    bounds: Option<Bounds2>,
+}
+
+impl<T> Default for SparseGrid<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> SparseGrid<T> {
+    pub fn new() -> Self {
+        Self {
+            cells: FxHashMap::default(),
+            bounds: None,
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.cells.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    // Smallest box around all occupied cells, None while the grid is empty.
+    pub fn bounds(&self) -> Option<&Bounds2> {
+        self.bounds.as_ref()
+    }
+
+    pub fn contains(&self, pos: &VecI2) -> bool {
+        self.cells.contains_key(pos)
+    }
+
+    pub fn get(&self, pos: &VecI2) -> Option<&T> {
+        self.cells.get(pos)
+    }
+
+    pub fn get_mut(&mut self, pos: &VecI2) -> Option<&mut T> {
+        self.cells.get_mut(pos)
+    }
+
+    // Returns `Option` like `Grid2d::set`, but never fails since the grid grows to fit.
+    pub fn set(&mut self, pos: &VecI2, value: T) -> Option<()> {
+        self.insert(pos, value);
+        Some(())
+    }
+
+    // Like `set`, but returns the previous value of the cell.
+    pub fn insert(&mut self, pos: &VecI2, value: T) -> Option<T> {
+        self.bounds.extend_mut(&Some(Bounds2::from_point(*pos)));
+        self.cells.insert(*pos, value)
+    }
+
+    pub fn remove(&mut self, pos: &VecI2) -> Option<T> {
+        let value = self.cells.remove(pos)?;
+        let on_edge = self.bounds.as_ref().is_some_and(|bounds| {
+            let min = bounds.min();
+            let max = bounds.max();
+            pos.0 == min.0 || pos.1 == min.1 || pos.0 == max.0 || pos.1 == max.1
+        });
+        if on_edge {
+            self.bounds = Bounds2::from_points(self.cells.keys().copied());
+        }
+        Some(value)
+    }
+
+    pub fn clear(&mut self) {
+        self.cells.clear();
+        self.bounds = None;
+    }
+
+    // In no particular order.
+    pub fn iter(&self) -> impl Iterator<Item = (VecI2, &T)> {
+        self.cells.iter().map(|(pos, t)| (*pos, t))
+    }
+
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = (VecI2, &mut T)> {
+        self.cells.iter_mut().map(|(pos, t)| (*pos, t))
+    }
+
+    // The occupied neighbors.
+    pub fn neighbors4(&self, pos: &VecI2) -> impl Iterator<Item = (VecI2, &T)> {
+        self.neighbors_at(pos, &NEIGHBORS4)
+    }
+
+    pub fn neighbors8(&self, pos: &VecI2) -> impl Iterator<Item = (VecI2, &T)> {
+        self.neighbors_at(pos, &NEIGHBORS8)
+    }
+
+    fn neighbors_at<'a>(
+        &'a self,
+        pos: &VecI2,
+        offsets: &'a [VecI2],
+    ) -> impl Iterator<Item = (VecI2, &'a T)> {
+        let pos = *pos;
+        offsets.iter().filter_map(move |offset| {
+            let next = pos + *offset;
+            self.get(&next).map(|t| (next, t))
+        })
+    }
+}
+
+impl<T> FromIterator<(VecI2, T)> for SparseGrid<T> {
+    fn from_iter<I: IntoIterator<Item = (VecI2, T)>>(iter: I) -> Self {
+        let mut grid = SparseGrid::new();
+        for (pos, t) in iter {
+            grid.set(&pos, t);
+        }
+        grid
+    }
+}
+
+impl<T> From<Grid2d<T>> for SparseGrid<T> {
+    fn from(grid: Grid2d<T>) -> Self {
+        let width = grid.width;
+        grid.vec
+            .into_iter()
+            .enumerate()
+            .map(|(i, t)| (VecI2((i % width) as i32, (i / width) as i32), t))
+            .collect()
+    }
+}
+
+// Empty cells inside the bounds are printed as '.'.
+impl<T: Display> Display for SparseGrid<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let Some(bounds) = &self.bounds else {
+            return Ok(());
+        };
+        for y in bounds.vertical().iter() {
+            for x in bounds.horizontal().iter() {
+                match self.get(&VecI2(x, y)) {
+                    Some(t) => write!(f, "{t}")?,
+                    None => write!(f, ".")?,
+                }
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
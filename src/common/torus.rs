@@ -0,0 +1,80 @@
+use super::{Grid2d, VecI2, VecI64, NEIGHBORS4, NEIGHBORS8};
+
+// A width x height coordinate space where leaving one edge enters at the opposite one. Every
+// position has a canonical cell with 0 <= x < width and 0 <= y < height.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Torus {
+    pub width: i32,
+    pub height: i32,
+}
+
+impl Torus {
+    pub fn new(width: i32, height: i32) -> Self {
+        assert!(width > 0 && height > 0, "Empty torus {}x{}", width, height);
+        Self { width, height }
+    }
+
+    pub fn wrap(&self, pos: &VecI2) -> VecI2 {
+        VecI2(pos.0.rem_euclid(self.width), pos.1.rem_euclid(self.height))
+    }
+
+    pub fn add(&self, pos: &VecI2, offset: &VecI2) -> VecI2 {
+        self.wrap(&(*pos + *offset))
+    }
+
+    // Where something moving by `velocity` per step ends up, without overflowing for large
+    // step counts.
+    pub fn step(&self, pos: &VecI2, velocity: &VecI2, steps: i64) -> VecI2 {
+        let size = VecI64(self.width as i64, self.height as i64);
+        let end = VecI64::from(*pos) + VecI64::from(*velocity) * steps;
+        end.rem_euclid(&size).try_into().unwrap()
+    }
+
+    pub fn neighbors4(&self, pos: &VecI2) -> impl Iterator<Item = VecI2> + '_ {
+        let pos = *pos;
+        NEIGHBORS4.iter().map(move |offset| self.add(&pos, offset))
+    }
+
+    pub fn neighbors8(&self, pos: &VecI2) -> impl Iterator<Item = VecI2> + '_ {
+        let pos = *pos;
+        NEIGHBORS8.iter().map(move |offset| self.add(&pos, offset))
+    }
+}
+
+// Access that treats the grid as a torus, any position maps to a cell.
+impl<T> Grid2d<T> {
+    pub fn torus(&self) -> Torus {
+        Torus::new(self.width as i32, self.height as i32)
+    }
+
+    pub fn get_wrapped(&self, pos: &VecI2) -> &T {
+        self.get(&self.torus().wrap(pos)).unwrap()
+    }
+
+    pub fn get_wrapped_mut(&mut self, pos: &VecI2) -> &mut T {
+        let pos = self.torus().wrap(pos);
+        self.get_mut(&pos).unwrap()
+    }
+
+    pub fn set_wrapped(&mut self, pos: &VecI2, value: T) {
+        *self.get_wrapped_mut(pos) = value;
+    }
+
+    pub fn neighbors4_wrapped(&self, pos: &VecI2) -> impl Iterator<Item = (VecI2, &T)> {
+        let torus = self.torus();
+        let pos = *pos;
+        NEIGHBORS4.iter().map(move |offset| {
+            let next = torus.add(&pos, offset);
+            (next, self.get(&next).unwrap())
+        })
+    }
+
+    pub fn neighbors8_wrapped(&self, pos: &VecI2) -> impl Iterator<Item = (VecI2, &T)> {
+        let torus = self.torus();
+        let pos = *pos;
+        NEIGHBORS8.iter().map(move |offset| {
+            let next = torus.add(&pos, offset);
+            (next, self.get(&next).unwrap())
+        })
+    }
+}
@@ -0,0 +1,328 @@
+use std::fmt::Display;
+
+use crate::geometry::{Bounded2, Bounds2};
+
+use super::{Grid2d, VecI2};
+
+// A transformed window into a grid that doesn't copy any cells. View position (x, y) maps to
+// `origin + x_axis * x + y_axis * y` in the grid. Only the grid cells inside `clip` are
+// visible, everything else reads as `pad`, or as missing if the view isn't padded.
+pub struct GridView<'a, T> {
+    grid: &'a Grid2d<T>,
+    origin: VecI2,
+    x_axis: VecI2,
+    y_axis: VecI2,
+    clip_min: VecI2,
+    clip_max: VecI2,
+    pad: Option<&'a T>,
+    pub width: usize,
+    pub height: usize,
+}
+
+impl<T> Clone for GridView<'_, T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for GridView<'_, T> {}
+
+impl<T> Grid2d<T> {
+    pub fn view(&self) -> GridView<'_, T> {
+        GridView {
+            grid: self,
+            origin: VecI2(0, 0),
+            x_axis: VecI2(1, 0),
+            y_axis: VecI2(0, 1),
+            clip_min: VecI2(0, 0),
+            clip_max: VecI2(self.width as i32 - 1, self.height as i32 - 1),
+            pad: None,
+            width: self.width,
+            height: self.height,
+        }
+    }
+}
+
+impl<T> Grid2d<T> {
+    // Every width x height window that fits into the grid, by top left corner in reading order.
+    pub fn windows(&self, width: usize, height: usize) -> impl Iterator<Item = GridView<'_, T>> {
+        let columns = (self.width + 1).saturating_sub(width);
+        let rows = (self.height + 1).saturating_sub(height);
+        let view = self.view();
+        (0..rows as i32)
+            .flat_map(move |y| (0..columns as i32).map(move |x| VecI2(x, y)))
+            .map(move |pos| view.window(&pos, width, height))
+    }
+}
+
+impl<T: Clone> Grid2d<T> {
+    pub fn rotate_cw(&self) -> Grid2d<T> {
+        self.view().rotate_cw().to_grid()
+    }
+
+    pub fn rotate_180(&self) -> Grid2d<T> {
+        self.view().rotate_180().to_grid()
+    }
+
+    pub fn rotate_ccw(&self) -> Grid2d<T> {
+        self.view().rotate_ccw().to_grid()
+    }
+
+    pub fn flip_horizontal(&self) -> Grid2d<T> {
+        self.view().flip_horizontal().to_grid()
+    }
+
+    pub fn flip_vertical(&self) -> Grid2d<T> {
+        self.view().flip_vertical().to_grid()
+    }
+
+    pub fn transpose(&self) -> Grid2d<T> {
+        self.view().transpose().to_grid()
+    }
+
+    // Parts of the bounds outside the grid are filled with `pad`.
+    pub fn crop(&self, bounds: &Bounds2, pad: T) -> Grid2d<T> {
+        let view = GridView {
+            pad: Some(&pad),
+            ..self.view()
+        };
+        view.crop(bounds).to_grid()
+    }
+
+    pub fn pad(&self, border: usize, value: T) -> Grid2d<T> {
+        self.view().pad(border, &value).to_grid()
+    }
+}
+
+impl<'a, T> GridView<'a, T> {
+    pub fn in_bounds(&self, pos: &VecI2) -> bool {
+        pos.0 >= 0 && pos.1 >= 0 && pos.0 < self.width as i32 && pos.1 < self.height as i32
+    }
+
+    // The grid position behind a view position, which may lie outside the grid.
+    pub fn to_grid_pos(self, pos: &VecI2) -> VecI2 {
+        self.origin + self.x_axis * pos.0 + self.y_axis * pos.1
+    }
+
+    pub fn get(&self, pos: &VecI2) -> Option<&'a T> {
+        if !self.in_bounds(pos) {
+            return None;
+        }
+        let grid_pos = self.to_grid_pos(pos);
+        if self.clipped(&grid_pos) {
+            self.pad
+        } else {
+            self.grid.get(&grid_pos)
+        }
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (VecI2, Option<&'a T>)> + '_ {
+        (0..self.height as i32)
+            .flat_map(|y| (0..self.width as i32).map(move |x| VecI2(x, y)))
+            .map(|pos| (pos, self.get(&pos)))
+    }
+
+    // New (x, y) is old (y, height - 1 - x).
+    pub fn rotate_cw(self) -> Self {
+        Self {
+            origin: self.to_grid_pos(&VecI2(0, self.height as i32 - 1)),
+            x_axis: self.y_axis * -1,
+            y_axis: self.x_axis,
+            width: self.height,
+            height: self.width,
+            ..self
+        }
+    }
+
+    pub fn rotate_180(self) -> Self {
+        Self {
+            origin: self.to_grid_pos(&VecI2(self.width as i32 - 1, self.height as i32 - 1)),
+            x_axis: self.x_axis * -1,
+            y_axis: self.y_axis * -1,
+            ..self
+        }
+    }
+
+    // New (x, y) is old (width - 1 - y, x).
+    pub fn rotate_ccw(self) -> Self {
+        Self {
+            origin: self.to_grid_pos(&VecI2(self.width as i32 - 1, 0)),
+            x_axis: self.y_axis,
+            y_axis: self.x_axis * -1,
+            width: self.height,
+            height: self.width,
+            ..self
+        }
+    }
+
+    // Mirrors left and right.
+    pub fn flip_horizontal(self) -> Self {
+        Self {
+            origin: self.to_grid_pos(&VecI2(self.width as i32 - 1, 0)),
+            x_axis: self.x_axis * -1,
+            ..self
+        }
+    }
+
+    // Mirrors top and bottom.
+    pub fn flip_vertical(self) -> Self {
+        Self {
+            origin: self.to_grid_pos(&VecI2(0, self.height as i32 - 1)),
+            y_axis: self.y_axis * -1,
+            ..self
+        }
+    }
+
+    pub fn transpose(self) -> Self {
+        Self {
+            x_axis: self.y_axis,
+            y_axis: self.x_axis,
+            width: self.height,
+            height: self.width,
+            ..self
+        }
+    }
+
+    // Bounds are in view coordinates. Parts of the bounds outside the view read as padding.
+    pub fn crop(self, bounds: &Bounds2) -> Self {
+        let min = bounds.min();
+        let max = bounds.max();
+        self.window(
+            &min,
+            (max.0 - min.0 + 1) as usize,
+            (max.1 - min.1 + 1) as usize,
+        )
+    }
+
+    pub fn window(self, pos: &VecI2, width: usize, height: usize) -> Self {
+        Self {
+            origin: self.to_grid_pos(pos),
+            width,
+            height,
+            ..self.clip_to_view()
+        }
+    }
+
+    pub fn pad(self, border: usize, value: &'a T) -> Self {
+        let border = border as i32;
+        Self {
+            origin: self.to_grid_pos(&VecI2(-border, -border)),
+            width: self.width + 2 * border as usize,
+            height: self.height + 2 * border as usize,
+            pad: Some(value),
+            ..self.clip_to_view()
+        }
+    }
+
+    pub fn to_grid(self) -> Grid2d<T>
+    where
+        T: Clone,
+    {
+        let vec = self
+            .iter()
+            .map(|(pos, t)| {
+                t.unwrap_or_else(|| panic!("View position {:?} is outside the grid", pos))
+                    .clone()
+            })
+            .collect();
+        Grid2d::new(self.width, self.height, vec)
+    }
+
+    fn clipped(&self, grid_pos: &VecI2) -> bool {
+        grid_pos.0 < self.clip_min.0
+            || grid_pos.1 < self.clip_min.1
+            || grid_pos.0 > self.clip_max.0
+            || grid_pos.1 > self.clip_max.1
+    }
+
+    // Hides the grid cells outside the current view, so that growing the view doesn't uncover
+    // them.
+    fn clip_to_view(self) -> Self {
+        if self.width == 0 || self.height == 0 {
+            return Self {
+                clip_min: VecI2(0, 0),
+                clip_max: VecI2(-1, -1),
+                ..self
+            };
+        }
+        let a = self.to_grid_pos(&VecI2(0, 0));
+        let b = self.to_grid_pos(&VecI2(self.width as i32 - 1, self.height as i32 - 1));
+        Self {
+            clip_min: VecI2::max(&self.clip_min, &VecI2::min(&a, &b)),
+            clip_max: VecI2::min(&self.clip_max, &VecI2::max(&a, &b)),
+            ..self
+        }
+    }
+}
+
+impl<T: Display> Display for GridView<'_, T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for y in 0..self.height as i32 {
+            for x in 0..self.width as i32 {
+                match self.get(&VecI2(x, y)) {
+                    Some(t) => write!(f, "{t}")?,
+                    None => write!(f, " ")?,
+                }
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+// The eight symmetries of a rectangle, rotations are clockwise.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Orientation {
+    Identity,
+    Rotate90,
+    Rotate180,
+    Rotate270,
+    FlipHorizontal,
+    FlipVertical,
+    Transpose,
+    AntiTranspose,
+}
+
+impl Orientation {
+    pub const ALL: [Orientation; 8] = [
+        Orientation::Identity,
+        Orientation::Rotate90,
+        Orientation::Rotate180,
+        Orientation::Rotate270,
+        Orientation::FlipHorizontal,
+        Orientation::FlipVertical,
+        Orientation::Transpose,
+        Orientation::AntiTranspose,
+    ];
+
+    pub fn apply<'a, T>(&self, view: GridView<'a, T>) -> GridView<'a, T> {
+        match self {
+            Orientation::Identity => view,
+            Orientation::Rotate90 => view.rotate_cw(),
+            Orientation::Rotate180 => view.rotate_180(),
+            Orientation::Rotate270 => view.rotate_ccw(),
+            Orientation::FlipHorizontal => view.flip_horizontal(),
+            Orientation::FlipVertical => view.flip_vertical(),
+            Orientation::Transpose => view.transpose(),
+            Orientation::AntiTranspose => view.transpose().rotate_180(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Symmetry {
+    Identity,
+    Rotations,
+    // rotations and reflections
+    All,
+}
+
+impl Symmetry {
+    pub fn orientations(&self) -> &'static [Orientation] {
+        match self {
+            Symmetry::Identity => &Orientation::ALL[..1],
+            Symmetry::Rotations => &Orientation::ALL[..4],
+            Symmetry::All => &Orientation::ALL,
+        }
+    }
+}
@@ -1,5 +1,7 @@
 #![allow(dead_code)]
-use crate::common::VecI2;
+use std::ops::RangeInclusive;
+
+use crate::common::{VecI2, VecI3};
 
 pub trait Bounded1
 where
@@ -13,7 +15,7 @@ where
     }
 
     fn center(&self) -> i32 {
-        (self.min() + self.size()) / 2
+        self.min() + self.size() / 2
     }
 }
 
@@ -29,7 +31,23 @@ where
     }
 
     fn center(&self) -> VecI2 {
-        (self.min() + self.size()) / 2
+        self.min() + self.size() / 2
+    }
+}
+
+pub trait Bounded3
+where
+    Self: Sized,
+{
+    fn min(&self) -> VecI3;
+    fn max(&self) -> VecI3;
+
+    fn size(&self) -> VecI3 {
+        self.max() - self.min()
+    }
+
+    fn center(&self) -> VecI3 {
+        self.min() + self.size() / 2
     }
 }
 
@@ -53,12 +71,60 @@ pub struct Bounds1 {
     max: i32,
 }
 
+// Bounds include both min and max, so they always contain at least one number. Where bounds
+// can be empty they're wrapped in an Option.
 impl Bounds1 {
-    fn new(number: i32) -> Self {
-        Self {
-            min: number,
-            max: number,
-        }
+    pub fn new(min: i32, max: i32) -> Self {
+        assert!(min <= max, "Invalid bounds {}..={}", min, max);
+        Self { min, max }
+    }
+
+    pub fn from_point(number: i32) -> Self {
+        Self::new(number, number)
+    }
+
+    // From a half open range, None if it's empty.
+    pub fn from_exclusive(start: i32, end: i32) -> Option<Self> {
+        (start < end).then(|| Self::new(start, end - 1))
+    }
+
+    pub fn from_values(values: impl IntoIterator<Item = i32>) -> Option<Self> {
+        values.into_iter().fold(None, |bounds, value| {
+            bounds.extend(&Some(Self::from_point(value)))
+        })
+    }
+
+    // The end of the half open range.
+    pub fn end(&self) -> i32 {
+        self.max + 1
+    }
+
+    // The number of values, one more than the size.
+    pub fn len(&self) -> i32 {
+        self.size() + 1
+    }
+
+    pub fn contains(&self, number: i32) -> bool {
+        self.min <= number && number <= self.max
+    }
+
+    pub fn contains_bounds(&self, other: &Self) -> bool {
+        self.min <= other.min && other.max <= self.max
+    }
+
+    pub fn intersect(&self, other: &Self) -> Option<Self> {
+        let min = self.min.max(other.min);
+        let max = self.max.min(other.max);
+        (min <= max).then(|| Self::new(min, max))
+    }
+
+    // The smallest bounds that contain both, including any gap between them.
+    pub fn union(&self, other: &Self) -> Self {
+        self.extend(other)
+    }
+
+    pub fn iter(&self) -> RangeInclusive<i32> {
+        self.min..=self.max
     }
 }
 
@@ -122,7 +188,83 @@ impl Bounds2 {
     }
 
     pub fn from_point(point: VecI2) -> Self {
-        Self::new(Bounds1::new(point.0), Bounds1::new(point.1))
+        Self::new(Bounds1::from_point(point.0), Bounds1::from_point(point.1))
+    }
+
+    // Any two opposite corners.
+    pub fn from_corners(a: VecI2, b: VecI2) -> Self {
+        let min = VecI2::min(&a, &b);
+        let max = VecI2::max(&a, &b);
+        Self::new(Bounds1::new(min.0, max.0), Bounds1::new(min.1, max.1))
+    }
+
+    // From a half open box, None if it's empty.
+    pub fn from_exclusive(min: VecI2, end: VecI2) -> Option<Self> {
+        Some(Self::new(
+            Bounds1::from_exclusive(min.0, end.0)?,
+            Bounds1::from_exclusive(min.1, end.1)?,
+        ))
+    }
+
+    pub fn from_points(points: impl IntoIterator<Item = VecI2>) -> Option<Self> {
+        points.into_iter().fold(None, |bounds, point| {
+            bounds.extend(&Some(Self::from_point(point)))
+        })
+    }
+
+    pub fn horizontal(&self) -> &Bounds1 {
+        &self.horizontal
+    }
+
+    pub fn vertical(&self) -> &Bounds1 {
+        &self.vertical
+    }
+
+    // The corner just outside, diagonally from max.
+    pub fn end(&self) -> VecI2 {
+        VecI2(self.horizontal.end(), self.vertical.end())
+    }
+
+    pub fn width(&self) -> i32 {
+        self.horizontal.len()
+    }
+
+    pub fn height(&self) -> i32 {
+        self.vertical.len()
+    }
+
+    // The number of points.
+    pub fn area(&self) -> i64 {
+        self.width() as i64 * self.height() as i64
+    }
+
+    pub fn contains(&self, point: &VecI2) -> bool {
+        self.horizontal.contains(point.0) && self.vertical.contains(point.1)
+    }
+
+    pub fn contains_bounds(&self, other: &Self) -> bool {
+        self.horizontal.contains_bounds(&other.horizontal)
+            && self.vertical.contains_bounds(&other.vertical)
+    }
+
+    pub fn intersect(&self, other: &Self) -> Option<Self> {
+        Some(Self::new(
+            self.horizontal.intersect(&other.horizontal)?,
+            self.vertical.intersect(&other.vertical)?,
+        ))
+    }
+
+    // The smallest bounds that contain both.
+    pub fn union(&self, other: &Self) -> Self {
+        self.extend(other)
+    }
+
+    // All points in reading order.
+    pub fn iter(&self) -> impl Iterator<Item = VecI2> {
+        let horizontal = self.horizontal.iter();
+        self.vertical
+            .iter()
+            .flat_map(move |y| horizontal.clone().map(move |x| VecI2(x, y)))
     }
 }
 
@@ -171,3 +313,72 @@ impl Extendable for Option<Bounds2> {
         }
     }
 }
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Bounds3 {
+    x: Bounds1,
+    y: Bounds1,
+    z: Bounds1,
+}
+
+impl Bounds3 {
+    pub fn new(x: Bounds1, y: Bounds1, z: Bounds1) -> Self {
+        Self { x, y, z }
+    }
+
+    pub fn from_point(point: VecI3) -> Self {
+        Self::new(
+            Bounds1::from_point(point.0),
+            Bounds1::from_point(point.1),
+            Bounds1::from_point(point.2),
+        )
+    }
+}
+
+impl Bounded3 for Bounds3 {
+    fn min(&self) -> VecI3 {
+        VecI3(self.x.min(), self.y.min(), self.z.min())
+    }
+
+    fn max(&self) -> VecI3 {
+        VecI3(self.x.max(), self.y.max(), self.z.max())
+    }
+}
+
+impl Extendable for Bounds3 {
+    fn extend(&self, other: &Self) -> Self {
+        Self {
+            x: self.x.extend(&other.x),
+            y: self.y.extend(&other.y),
+            z: self.z.extend(&other.z),
+        }
+    }
+
+    fn extend_mut(&mut self, other: &Self) {
+        self.x.extend_mut(&other.x);
+        self.y.extend_mut(&other.y);
+        self.z.extend_mut(&other.z);
+    }
+}
+
+impl Extendable for Option<Bounds3> {
+    fn extend(&self, other: &Self) -> Self {
+        if self.is_none() {
+            other.clone()
+        } else if other.is_none() {
+            self.clone()
+        } else {
+            Some(self.as_ref().unwrap().extend(other.as_ref().unwrap()))
+        }
+    }
+
+    fn extend_mut(&mut self, other: &Self) {
+        if self.is_none() {
+            *self = other.clone();
+        } else if other.is_none() {
+            // NOP
+        } else {
+            *self = Some(self.as_ref().unwrap().extend(other.as_ref().unwrap()))
+        }
+    }
+}
@@ -1,14 +1,21 @@
 use std::{
+    env, fs,
+    hint::black_box,
     thread,
     time::{Duration, Instant},
     vec,
 };
 
-use common::{PuzzleInput, Solution};
+use common::{
+    image::{FrameWriter, ImageFormat},
+    BorrowedPuzzleInput, PuzzleInput, Solution,
+};
 use itertools::Itertools;
+use playback::Recording;
 
 mod common;
 mod geometry;
+mod playback;
 mod s_01;
 mod s_02;
 mod s_03;
@@ -37,7 +44,45 @@ mod s_24;
 mod s_25;
 
 fn main() {
-    let solutions: Vec<Vec<Box<dyn Solution>>> = vec![
+    let args = env::args().collect::<Vec<_>>();
+    match args.get(1).map(String::as_str) {
+        Some("bench-input") => {
+            bench_input();
+            return;
+        }
+        Some("export") => {
+            export(args.get(2), args.get(3));
+            return;
+        }
+        Some("play") => {
+            play(args.get(2), args.get(3));
+            return;
+        }
+        _ => {}
+    }
+
+    // newest first
+    let solutions_per_day = solutions()
+        .into_iter()
+        .enumerate()
+        .map(|(i, solution)| (i + 1, solution))
+        .sorted_by(|a, b| a.0.cmp(&b.0))
+        .rev()
+        .collect::<Vec<_>>();
+
+    let mut wait = true;
+    for (i, solutions) in solutions_per_day {
+        let result = solve(solutions, format!("{:02}", i).as_str());
+        if result.is_some() && wait {
+            // wait a few seconds after printing the latest solution
+            thread::sleep(Duration::from_secs(5));
+            wait = false;
+        }
+    }
+}
+
+fn solutions() -> Vec<Vec<Box<dyn Solution>>> {
+    vec![
         vec![Box::new(s_01::S)],
         vec![Box::new(s_02::S)],
         vec![Box::new(s_03::S), Box::new(s_03_2::S)],
@@ -63,35 +108,15 @@ fn main() {
         vec![Box::new(s_23::S)],
         vec![Box::new(s_24::S)],
         vec![Box::new(s_25::S)],
-    ];
-
-    // newest first
-    let solutions_per_day = solutions
-        .into_iter()
-        .enumerate()
-        .map(|(i, solution)| (i + 1, solution))
-        .sorted_by(|a, b| a.0.cmp(&b.0))
-        .rev()
-        .collect::<Vec<_>>();
-
-    let mut wait = true;
-    for (i, solutions) in solutions_per_day {
-        let result = solve(solutions, format!("{:02}", i).as_str());
-        if result.is_some() && wait {
-            // wait a few seconds after printing the latest solution
-            thread::sleep(Duration::from_secs(5));
-            wait = false;
-        }
-    }
+    ]
 }
 
 fn solve(solutions: Vec<Box<dyn Solution>>, day: &str) -> Option<()> {
-    let input = PuzzleInput::new(&format!("input/{}.txt", day));
-    input.as_ref()?;
-    let input = input.unwrap();
-    if input.input.is_empty() {
+    let text = fs::read_to_string(format!("input/{}.txt", day)).unwrap_or_default();
+    if text.is_empty() {
         return None;
     }
+    let input = PuzzleInput::borrowed(&text);
 
     println!("\nDecember {}, 2024", day);
 
@@ -108,7 +133,7 @@ fn solve(solutions: Vec<Box<dyn Solution>>, day: &str) -> Option<()> {
         let expected_output = solution.expected_output_one();
 
         if !test_input.is_empty() {
-            let test_input = PuzzleInput::from_str(test_input).unwrap();
+            let test_input = PuzzleInput::borrowed(test_input);
             let actual_output = solution.solve_one(&test_input);
             if !actual_output.is_empty() {
                 assert_eq!(actual_output, expected_output, "test for part one failed");
@@ -133,7 +158,7 @@ fn solve(solutions: Vec<Box<dyn Solution>>, day: &str) -> Option<()> {
         let expected_output = solution.expected_output_two();
 
         if !test_input.is_empty() {
-            let test_input = PuzzleInput::from_str(test_input).unwrap();
+            let test_input = PuzzleInput::borrowed(test_input);
             let actual_output = solution.solve_two(&test_input);
             if !actual_output.is_empty() {
                 assert_eq!(actual_output, expected_output, "test for part two failed");
@@ -150,3 +175,97 @@ fn solve(solutions: Vec<Box<dyn Solution>>, day: &str) -> Option<()> {
     }
     Some(())
 }
+
+// The first solution of a day and the text of its input.
+fn simulation(day: Option<&String>) -> Option<(usize, Box<dyn Solution>, String)> {
+    let Some(day) = day.and_then(|d| d.parse::<usize>().ok()) else {
+        eprintln!("Missing or invalid day");
+        return None;
+    };
+    let Some(solution) = solutions()
+        .into_iter()
+        .nth(day.wrapping_sub(1))
+        .and_then(|s| s.into_iter().next())
+    else {
+        eprintln!("No solution for day {}", day);
+        return None;
+    };
+    let text = fs::read_to_string(format!("input/{:02}.txt", day)).unwrap_or_default();
+    if text.is_empty() {
+        eprintln!("No input for day {}", day);
+        return None;
+    }
+    Some((day, solution, text))
+}
+
+// Writes the frames of a day's simulation as PNG images.
+// Run with `cargo run --release -- export <day> [dir]`, the directory defaults to
+// `target/export/<day>`.
+fn export(day: Option<&String>, dir: Option<&String>) {
+    let Some((day, solution, text)) = simulation(day) else {
+        return;
+    };
+    let input = PuzzleInput::borrowed(&text);
+    let dir = dir
+        .cloned()
+        .unwrap_or_else(|| format!("target/export/{:02}", day));
+
+    let mut writer = FrameWriter::new(&dir, ImageFormat::Png).unwrap();
+    if solution.simulate(&input, &mut writer) {
+        println!("Wrote {} frames to {}", writer.count(), dir);
+    } else {
+        eprintln!("Day {} is not a simulation", day);
+    }
+}
+
+// Replays a day's simulation in the terminal.
+// Run with `cargo run --release -- play <day> [frame]`.
+fn play(day: Option<&String>, start: Option<&String>) {
+    let Some((day, solution, text)) = simulation(day) else {
+        return;
+    };
+    let input = PuzzleInput::borrowed(&text);
+    let start = start.and_then(|s| s.parse().ok()).unwrap_or(0);
+
+    let mut recording = Recording::new();
+    if solution.simulate(&input, &mut recording) {
+        playback::play(&recording, start);
+    } else {
+        eprintln!("Day {} is not a simulation", day);
+    }
+}
+
+// Compares the time it takes to build an owned and a borrowed PuzzleInput for every day.
+// Run with `cargo run --release -- bench-input`.
+fn bench_input() {
+    const ITERATIONS: u32 = 1000;
+
+    println!("Day   Lines   Bytes      Owned   Borrowed");
+    for day in 1..=25 {
+        let input = fs::read_to_string(format!("input/{:02}.txt", day)).unwrap_or_default();
+        if input.is_empty() {
+            continue;
+        }
+
+        let start = Instant::now();
+        for _ in 0..ITERATIONS {
+            black_box(PuzzleInput::from_str(black_box(&input)));
+        }
+        let owned = start.elapsed() / ITERATIONS;
+
+        let start = Instant::now();
+        for _ in 0..ITERATIONS {
+            black_box(BorrowedPuzzleInput::borrowed(black_box(&input)));
+        }
+        let borrowed = start.elapsed() / ITERATIONS;
+
+        println!(
+            "{:02} {:>8} {:>7} {:>10.1?} {:>10.1?}",
+            day,
+            input.lines().count(),
+            input.len(),
+            owned,
+            borrowed
+        );
+    }
+}
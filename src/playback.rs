@@ -0,0 +1,163 @@
+use std::{
+    io::{self, BufRead, IsTerminal, Write},
+    sync::mpsc::{self, RecvTimeoutError},
+    thread,
+    time::Duration,
+};
+
+use crate::common::{render::Color, FrameSink, Grid2d};
+
+// Keeps every frame of a simulation as text, which takes a quarter of the memory of the frame
+// grids for long simulations.
+#[derive(Debug, Default)]
+pub struct Recording {
+    frames: Vec<String>,
+}
+
+impl Recording {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn len(&self) -> usize {
+        self.frames.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.frames.is_empty()
+    }
+
+    pub fn get(&self, index: usize) -> Option<Grid2d<char>> {
+        let lines = self.frames.get(index)?.lines().collect::<Vec<_>>();
+        Some(Grid2d::from_lines(&lines, |c| c))
+    }
+}
+
+impl FrameSink for Recording {
+    fn frame(&mut self, frame: &Grid2d<char>) {
+        self.frames.push(frame.to_string());
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Command {
+    TogglePause,
+    Step(isize),
+    Faster,
+    Slower,
+    Jump(usize),
+    Quit,
+}
+
+const HELP: &str = "enter: pause, n/b: step, +/-: speed, g <frame>: jump, q: quit";
+
+fn parse_command(line: &str) -> Option<Command> {
+    let line = line.trim();
+    match line {
+        "" | "p" => Some(Command::TogglePause),
+        "n" => Some(Command::Step(1)),
+        "b" => Some(Command::Step(-1)),
+        "+" => Some(Command::Faster),
+        "-" => Some(Command::Slower),
+        "q" => Some(Command::Quit),
+        _ => line
+            .strip_prefix('g')
+            .and_then(|n| n.trim().parse().ok())
+            .map(Command::Jump),
+    }
+}
+
+fn color(c: &char) -> Option<Color> {
+    match c {
+        '#' => Some(Color::Gray),
+        'O' | '[' | ']' => Some(Color::Yellow),
+        '@' | '^' | '>' | 'v' | '<' => Some(Color::Red),
+        'X' => Some(Color::Blue),
+        _ => None,
+    }
+}
+
+// Plays the frames in the terminal. Commands are read from stdin one line at a time, so this
+// works without a raw mode terminal. Stops at the last frame and returns once stdin is closed.
+pub fn play(recording: &Recording, start: usize) {
+    if recording.is_empty() {
+        return;
+    }
+
+    let (sender, receiver) = mpsc::channel();
+    thread::spawn(move || {
+        for line in io::stdin().lock().lines() {
+            let Ok(line) = line else {
+                break;
+            };
+            match parse_command(&line) {
+                Some(command) => {
+                    if sender.send(command).is_err() {
+                        break;
+                    }
+                }
+                None => eprintln!("Unknown command {:?}, {}", line, HELP),
+            }
+        }
+    });
+
+    let last = recording.len() - 1;
+    let mut frame = start.min(last);
+    let mut paused = false;
+    let mut fps = 10.0;
+    loop {
+        show(recording, frame, fps, paused);
+
+        let command = if paused {
+            match receiver.recv() {
+                Ok(command) => Some(command),
+                Err(_) => return,
+            }
+        } else {
+            let delay = Duration::from_secs_f64(1.0 / fps);
+            match receiver.recv_timeout(delay) {
+                Ok(command) => Some(command),
+                Err(RecvTimeoutError::Timeout) => None,
+                Err(RecvTimeoutError::Disconnected) => {
+                    thread::sleep(delay);
+                    None
+                }
+            }
+        };
+
+        match command {
+            None if frame < last => frame += 1,
+            None => paused = true,
+            Some(Command::TogglePause) => paused = !paused,
+            Some(Command::Step(step)) => {
+                paused = true;
+                frame = frame.saturating_add_signed(step).min(last);
+            }
+            Some(Command::Faster) => fps = (fps * 2.0f64).min(1000.0),
+            Some(Command::Slower) => fps = (fps / 2.0f64).max(0.25),
+            Some(Command::Jump(n)) => frame = n.min(last),
+            Some(Command::Quit) => return,
+        }
+    }
+}
+
+fn show(recording: &Recording, index: usize, fps: f64, paused: bool) {
+    let frame = recording.get(index).unwrap();
+    let mut stdout = io::stdout().lock();
+    if stdout.is_terminal() {
+        // clear the screen and move to the top left corner
+        write!(stdout, "\x1b[2J\x1b[H").unwrap();
+    }
+    write!(stdout, "{}", frame.renderer().base_color(color)).unwrap();
+    writeln!(
+        stdout,
+        "frame {}/{}  {} fps{}  ({})",
+        index,
+        recording.len() - 1,
+        fps,
+        if paused { "  paused" } else { "" },
+        HELP
+    )
+    .unwrap();
+    stdout.flush().unwrap();
+}
@@ -13,8 +13,8 @@ const TEST: &str = r#"3   4
 "#;
 
 impl Solution for S {
-    fn solve_one(&self, input: &PuzzleInput) -> String {
-        let (mut list_one, mut list_two) = parse_columns(input);
+    fn solve_one(&self, input: &BorrowedPuzzleInput) -> String {
+        let [mut list_one, mut list_two] = input.columns::<i32, 2>();
 
         list_one.sort();
         list_two.sort();
@@ -36,8 +36,8 @@ impl Solution for S {
         "11"
     }
 
-    fn solve_two(&self, input: &PuzzleInput) -> String {
-        let (list_one, list_two) = parse_columns(input);
+    fn solve_two(&self, input: &BorrowedPuzzleInput) -> String {
+        let [list_one, list_two] = input.columns::<i32, 2>();
 
         let frequency_map = list_two.iter().fold(HashMap::new(), |mut map, item| {
             *map.entry(*item).or_insert(0) += 1;
@@ -62,15 +62,3 @@ impl Solution for S {
         "31"
     }
 }
-
-fn parse_columns(input: &PuzzleInput) -> (Vec<i32>, Vec<i32>) {
-    let mut list_one = Vec::new();
-    let mut list_two = Vec::new();
-
-    for line in &input.lines {
-        let parts = line.split_whitespace().collect::<Vec<&str>>();
-        list_one.push(parts[0].parse::<i32>().unwrap());
-        list_two.push(parts[1].parse::<i32>().unwrap());
-    }
-    (list_one, list_two)
-}
@@ -11,7 +11,7 @@ const TEST: &str = r#"7 6 4 2 1
 "#;
 
 impl Solution for S {
-    fn solve_one(&self, input: &PuzzleInput) -> String {
+    fn solve_one(&self, input: &BorrowedPuzzleInput) -> String {
         let lines = input.parsed2d::<i32>();
         let safe_lines = lines.iter().filter(|line| is_safe(line)).count();
         safe_lines.to_string()
@@ -25,7 +25,7 @@ impl Solution for S {
         "2"
     }
 
-    fn solve_two(&self, input: &PuzzleInput) -> String {
+    fn solve_two(&self, input: &BorrowedPuzzleInput) -> String {
         let lines = input.parsed2d::<i32>();
         let safe_lines = lines.iter().filter(|line| is_safe_dampened(line)).count();
         safe_lines.to_string()
@@ -27,7 +27,7 @@ struct Operation {
 pub struct S;
 
 impl Solution for S {
-    fn solve_one(&self, input: &PuzzleInput) -> String {
+    fn solve_one(&self, input: &BorrowedPuzzleInput) -> String {
         let operations = parse_operations(input);
 
         let result: i32 = operations
@@ -49,7 +49,7 @@ impl Solution for S {
         "161"
     }
 
-    fn solve_two(&self, input: &PuzzleInput) -> String {
+    fn solve_two(&self, input: &BorrowedPuzzleInput) -> String {
         let operations = parse_operations(input);
 
         let mut result = 0;
@@ -79,7 +79,7 @@ impl Solution for S {
     }
 }
 
-fn parse_operations(input: &PuzzleInput) -> Vec<Operation> {
+fn parse_operations(input: &BorrowedPuzzleInput) -> Vec<Operation> {
     let mut operations = vec![];
     for line in input.lines.iter() {
         let mut line_operations = vec![];
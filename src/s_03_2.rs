@@ -52,9 +52,9 @@ impl Context {
 pub struct S;
 
 impl Solution for S {
-    fn solve_one(&self, input: &PuzzleInput) -> String {
+    fn solve_one(&self, input: &BorrowedPuzzleInput) -> String {
         let operations = parse_mul;
-        parse_operations_and_sum(&input.input, operations).to_string()
+        parse_operations_and_sum(input.input, operations).to_string()
     }
 
     fn test_input_one(&self) -> &str {
@@ -65,9 +65,9 @@ impl Solution for S {
         "161"
     }
 
-    fn solve_two(&self, input: &PuzzleInput) -> String {
+    fn solve_two(&self, input: &BorrowedPuzzleInput) -> String {
         let operations = alt((parse_mul, parse_do, parse_dont));
-        parse_operations_and_sum(&input.input, operations).to_string()
+        parse_operations_and_sum(input.input, operations).to_string()
     }
 
     fn test_input_two(&self) -> &str {
@@ -11,7 +11,7 @@ use crate::common::*;
 pub struct S;
 
 impl Solution for S {
-    fn solve_one(&self, input: &PuzzleInput) -> String {
+    fn solve_one(&self, input: &BorrowedPuzzleInput) -> String {
         let mut rules = vec![];
         let mut updates = vec![];
 
@@ -111,7 +111,7 @@ impl Solution for S {
         "143"
     }
 
-    fn solve_two(&self, input: &PuzzleInput) -> String {
+    fn solve_two(&self, input: &BorrowedPuzzleInput) -> String {
         let mut rules = vec![];
         let mut updates = vec![];
 
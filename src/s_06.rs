@@ -1,7 +1,7 @@
 use rayon::prelude::*;
 
-use crate::common::*;
-use std::{collections::HashSet, fmt::Display};
+use crate::common::{bits::DirectionGrid, *};
+use std::fmt::Display;
 
 pub struct S;
 
@@ -39,45 +39,34 @@ struct Guard {
 pub struct Puzzle {
     guard: Option<Guard>,
     additional_obstacle: Option<VecI2>,
-    visited: HashSet<VecI2>,
-    visited_with_dir: HashSet<Guard>,
+    visited: DirectionGrid,
 }
 
 impl Puzzle {
-    fn new(input: &PuzzleInput, width: usize) -> Self {
-        let guard = input
-            .input
-            .chars()
-            .filter(|c| !c.is_whitespace())
-            .enumerate()
-            .filter_map(|(i, c)| Direction::from_char(c).map(|direction| (i, direction)))
-            .map(|(i, direction)| {
-                let pos = VecI2((i % width) as i32, (i / width) as i32);
-                Guard { pos, direction }
-            })
-            .next();
-
-        if guard.is_none() {
-            panic!("No guard found");
-        }
-
+    fn new(guard: Guard, grid: &Grid2d<MapTile>) -> Self {
         Self {
-            guard,
+            guard: Some(guard),
             additional_obstacle: None,
-            visited: HashSet::new(),
-            visited_with_dir: HashSet::new(),
+            visited: DirectionGrid::new(grid.width, grid.height),
         }
     }
 
     fn solve(&mut self, grid: &Grid2d<MapTile>) -> Option<usize> {
+        self.solve_with(grid, |_| {})
+    }
+
+    // Calls `on_step` with the state before every step.
+    fn solve_with(
+        &mut self,
+        grid: &Grid2d<MapTile>,
+        mut on_step: impl FnMut(&Self),
+    ) -> Option<usize> {
         while self.guard.is_some() {
+            on_step(self);
             let guard = self.guard.unwrap();
-            self.visited.insert(guard.pos);
-
-            if self.visited_with_dir.contains(&guard) {
+            if !self.visited.insert(&guard.pos, &guard.direction) {
                 return None;
             }
-            self.visited_with_dir.insert(guard);
 
             let next_pos = guard.pos.dir(&guard.direction);
 
@@ -92,27 +81,36 @@ impl Puzzle {
                 } else {
                     self.guard = Some(Guard {
                         pos: guard.pos,
-                        direction: match guard.direction {
-                            Direction::Up => Direction::Right,
-                            Direction::Right => Direction::Down,
-                            Direction::Down => Direction::Left,
-                            Direction::Left => Direction::Up,
-                        },
+                        direction: guard.direction.turn_right(),
                     });
                 }
             } else {
                 self.guard = None;
             }
         }
-        //println!("{}", grid);
-        Some(self.visited.len())
+        Some(self.visited.visited_cells().count())
+    }
+
+    fn render(&self, grid: &Grid2d<MapTile>) -> Grid2d<char> {
+        let mut frame = grid.map(|tile| match tile {
+            MapTile::Empty => '.',
+            MapTile::Obstacle => '#',
+        });
+        for pos in self.visited.visited_cells().iter() {
+            frame.set(&pos, 'X');
+        }
+        if let Some(guard) = self.guard {
+            let c = guard.direction.to_string().chars().next().unwrap();
+            frame.set(&guard.pos, c);
+        }
+        frame
     }
 }
 
 impl Solution for S {
-    fn solve_one(&self, input: &PuzzleInput) -> String {
-        let grid = input.grid2d(MapTile::from_char);
-        let mut puzzle = Puzzle::new(input, grid.width);
+    fn solve_one(&self, input: &BorrowedPuzzleInput) -> String {
+        let (grid, guard) = parse(input);
+        let mut puzzle = Puzzle::new(guard, &grid);
         let result = puzzle.solve(&grid).unwrap();
         result.to_string()
     }
@@ -134,16 +132,17 @@ impl Solution for S {
         "41"
     }
 
-    fn solve_two(&self, input: &PuzzleInput) -> String {
-        let grid = input.grid2d(MapTile::from_char);
-        let puzzle = Puzzle::new(input, grid.width);
+    fn solve_two(&self, input: &BorrowedPuzzleInput) -> String {
+        let (grid, guard) = parse(input);
+        let puzzle = Puzzle::new(guard, &grid);
         let mut solved_puzzle = puzzle.clone();
         solved_puzzle.solve(&grid).unwrap();
 
-        let guard_pos = puzzle.guard.unwrap().pos;
+        let guard_pos = guard.pos;
         let candiates = solved_puzzle
             .visited
-            .into_iter()
+            .visited_cells()
+            .iter()
             .filter(|p| p != &guard_pos)
             .collect::<Vec<_>>();
 
@@ -178,4 +177,30 @@ impl Solution for S {
     fn expected_output_two(&self) -> &str {
         "6"
     }
+
+    fn simulate(&self, input: &BorrowedPuzzleInput, sink: &mut dyn FrameSink) -> bool {
+        let (grid, guard) = parse(input);
+        let mut puzzle = Puzzle::new(guard, &grid);
+        puzzle.solve_with(&grid, |puzzle| sink.frame(&puzzle.render(&grid)));
+        sink.frame(&puzzle.render(&grid));
+        true
+    }
+}
+
+const GUARDS: [char; 4] = ['^', '>', 'v', '<'];
+
+fn parse(input: &BorrowedPuzzleInput) -> (Grid2d<MapTile>, Guard) {
+    let (grid, markers) = input
+        .grid2d_with_markers(&GUARDS.map(Marker::Many), '.', MapTile::from_char)
+        .unwrap();
+    let mut guards = GUARDS
+        .iter()
+        .flat_map(|c| markers.positions(*c).iter().map(move |pos| (*pos, *c)));
+    let (pos, c) = guards.next().expect("No guard found");
+    assert!(guards.next().is_none(), "More than one guard found");
+    let guard = Guard {
+        pos,
+        direction: Direction::from_char(c).unwrap(),
+    };
+    (grid, guard)
 }
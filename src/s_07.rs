@@ -8,7 +8,7 @@ use crate::common::*;
 pub struct S;
 
 impl Solution for S {
-    fn solve_one(&self, input: &PuzzleInput) -> String {
+    fn solve_one(&self, input: &BorrowedPuzzleInput) -> String {
         const OPERATORS: [char; 2] = ['+', '*'];
         solve(input, &OPERATORS).to_string()
     }
@@ -30,7 +30,7 @@ impl Solution for S {
         "3749"
     }
 
-    fn solve_two(&self, input: &PuzzleInput) -> String {
+    fn solve_two(&self, input: &BorrowedPuzzleInput) -> String {
         const OPERATORS: [char; 3] = ['+', '*', '|'];
         solve(input, &OPERATORS).to_string()
     }
@@ -53,7 +53,7 @@ impl Solution for S {
     }
 }
 
-fn solve(input: &PuzzleInput, operators: &[char]) -> u64 {
+fn solve(input: &BorrowedPuzzleInput, operators: &[char]) -> u64 {
     input
         .lines
         .par_iter()
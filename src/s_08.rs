@@ -26,6 +26,13 @@ impl Location {
         matches!(self, Self::Antenna(_) | Self::AntennaWithAntinode(_))
     }
 
+    fn with_antinode(&self) -> Self {
+        match self {
+            Self::Empty | Self::EmptyWithAntinode => Self::EmptyWithAntinode,
+            Self::Antenna(c) | Self::AntennaWithAntinode(c) => Self::AntennaWithAntinode(*c),
+        }
+    }
+
     fn is_same_antenna_type(&self, other: &Self) -> bool {
         match (self, other) {
             (Self::Antenna(c1), Self::Antenna(c2)) => c1 == c2,
@@ -47,22 +54,15 @@ impl Display for Location {
 }
 
 impl Solution for S {
-    fn solve_one(&self, input: &PuzzleInput) -> String {
+    fn solve_one(&self, input: &BorrowedPuzzleInput) -> String {
         let grid = input.grid2d(Location::from_char);
         let mut clone = grid.clone();
 
         for (p, p2) in pairs(&grid) {
             let antinode_location = p - (p2 - p);
 
-            if clone.in_bounds(&antinode_location) {
-                let a = clone.get(&antinode_location).unwrap();
-                let new_location = match a {
-                    Location::Empty => Location::EmptyWithAntinode,
-                    Location::EmptyWithAntinode => Location::EmptyWithAntinode,
-                    Location::Antenna(c) => Location::AntennaWithAntinode(*c),
-                    Location::AntennaWithAntinode(c) => Location::AntennaWithAntinode(*c),
-                };
-                clone.set(&antinode_location, new_location);
+            if let Some(location) = clone.get_mut(&antinode_location) {
+                *location = location.with_antinode();
             }
         }
 
@@ -99,22 +99,15 @@ impl Solution for S {
         "14"
     }
 
-    fn solve_two(&self, input: &PuzzleInput) -> String {
+    fn solve_two(&self, input: &BorrowedPuzzleInput) -> String {
         let grid = input.grid2d(Location::from_char);
         let mut clone = grid.clone();
 
         for (p, p2) in pairs(&grid) {
             let diff = p2 - p;
             let mut antinode_location = p;
-            while clone.in_bounds(&antinode_location) {
-                let a = clone.get(&antinode_location).unwrap();
-                let new_location = match a {
-                    Location::Empty => Location::EmptyWithAntinode,
-                    Location::EmptyWithAntinode => Location::EmptyWithAntinode,
-                    Location::Antenna(c) => Location::AntennaWithAntinode(*c),
-                    Location::AntennaWithAntinode(c) => Location::AntennaWithAntinode(*c),
-                };
-                clone.set(&antinode_location, new_location);
+            while let Some(location) = clone.get_mut(&antinode_location) {
+                *location = location.with_antinode();
                 antinode_location = antinode_location - diff;
             }
         }
@@ -38,7 +38,7 @@ impl Display for Block {
 }
 
 impl Solution for S {
-    fn solve_one(&self, input: &PuzzleInput) -> String {
+    fn solve_one(&self, input: &BorrowedPuzzleInput) -> String {
         let mut blocks = parse_blocks(input);
         let mut left = 0;
         let mut right = blocks.len() - 1;
@@ -66,14 +66,10 @@ impl Solution for S {
         "1928"
     }
 
-    fn solve_two(&self, input: &PuzzleInput) -> String {
+    fn solve_two(&self, input: &BorrowedPuzzleInput) -> String {
         let mut blocks: Vec<Block2> = vec![];
         let mut is_block = true;
-        for (i, c) in input.lines[0]
-            .chars()
-            .map(|c| c.to_digit(10).unwrap())
-            .enumerate()
-        {
+        for (i, c) in input.digits().into_iter().enumerate() {
             if is_block {
                 blocks.push(Block2::Used(i / 2, c as usize));
             } else {
@@ -154,14 +150,10 @@ impl Solution for S {
     }
 }
 
-fn parse_blocks(input: &PuzzleInput) -> Vec<Block> {
+fn parse_blocks(input: &BorrowedPuzzleInput) -> Vec<Block> {
     let mut blocks: Vec<Block> = vec![];
     let mut is_empty = false;
-    for (i, c) in input.lines[0]
-        .chars()
-        .map(|c| c.to_digit(10).unwrap())
-        .enumerate()
-    {
+    for (i, c) in input.digits().into_iter().enumerate() {
         for _ in 0..c {
             if is_empty {
                 blocks.push(Block::Empty);
@@ -1,118 +1,33 @@
-use std::collections::HashSet;
-
 use crate::common::*;
 
 pub struct S;
 
-#[derive(Debug, Clone, PartialEq, Eq)]
-pub struct Trail {
-    pub trail: Vec<VecI2>,
-    pub visited: HashSet<VecI2>,
+fn uphill<'a>(grid: &'a Grid2d<u8>, pos: &VecI2) -> impl Iterator<Item = VecI2> + 'a {
+    let value = *grid.get(pos).unwrap();
+    grid.neighbors4(pos)
+        .filter(move |(_, v)| **v == value + 1)
+        .map(|(p, _)| p)
 }
 
-impl Trail {
-    pub fn already_visited(&self, pos: &VecI2) -> bool {
-        self.visited.contains(pos)
-    }
-
-    pub fn visit(&mut self, pos: &VecI2) {
-        self.trail.push(*pos);
-        self.visited.insert(*pos);
-    }
-}
-
-fn get_trailheads(pos: VecI2, grid: &Grid2d<u8>, visited: &mut HashSet<VecI2>) -> u32 {
-    //let mut visited = visited.clone();
-    let value = grid.get(&pos);
-    //println!("{:?} {:?}", pos, value);
-    if value.is_none() || visited.contains(&pos) {
-        return 0;
-    }
-    let value = value.unwrap();
-
-    visited.insert(pos);
-    //println!("{:?}", visited);
-
-    if value == &9 {
-        return 1;
-    }
-
-    let mut sum = 0;
-
-    let left = pos.left();
-    if grid.in_bounds(&left) && *grid.get(&left).unwrap() == value + 1 {
-        sum += get_trailheads(left, grid, visited);
-    }
-    let up = pos.up();
-    if grid.in_bounds(&up) && *grid.get(&up).unwrap() == value + 1 {
-        sum += get_trailheads(up, grid, visited);
-    }
-    let right = pos.right();
-    if grid.in_bounds(&right) && *grid.get(&right).unwrap() == value + 1 {
-        sum += get_trailheads(right, grid, visited);
-    }
-    let down = pos.down();
-    if grid.in_bounds(&down) && *grid.get(&down).unwrap() == value + 1 {
-        sum += get_trailheads(down, grid, visited);
-    }
-
-    sum
-}
-
-fn get_trailheads2(pos: VecI2, grid: &Grid2d<u8>, trail: &mut Trail) -> Vec<Trail> {
-    let mut trail = trail.clone();
-    let value = grid.get(&pos);
-    //println!("{:?} {:?}", pos, value);
-    if value.is_none() || trail.already_visited(&pos) {
-        return vec![];
-    }
-    let value = value.unwrap();
-
-    trail.visit(&pos);
-    //println!("{:?}", visited);
-
-    if value == &9 {
-        return vec![trail];
-    }
-
-    let mut trails = vec![];
-
-    let left = pos.left();
-    if grid.in_bounds(&left) && *grid.get(&left).unwrap() == value + 1 {
-        trails.extend(get_trailheads2(left, grid, &mut trail));
-    }
-    let up = pos.up();
-    if grid.in_bounds(&up) && *grid.get(&up).unwrap() == value + 1 {
-        trails.extend(get_trailheads2(up, grid, &mut trail));
-    }
-    let right = pos.right();
-    if grid.in_bounds(&right) && *grid.get(&right).unwrap() == value + 1 {
-        trails.extend(get_trailheads2(right, grid, &mut trail));
-    }
-    let down = pos.down();
-    if grid.in_bounds(&down) && *grid.get(&down).unwrap() == value + 1 {
-        trails.extend(get_trailheads2(down, grid, &mut trail));
-    }
-
-    trails
+// Every step climbs by one, so each trail is also a shortest path from its trailhead.
+fn trails(grid: &Grid2d<u8>) -> impl Iterator<Item = search::ShortestPaths<VecI2, usize>> + '_ {
+    grid.iter()
+        .filter(|(_, v)| **v == 0)
+        .map(|(start, _)| search::bfs(start, |p| uphill(grid, p)))
 }
 
 impl Solution for S {
-    fn solve_one(&self, input: &PuzzleInput) -> String {
-        let grid = input.grid2d(|c| c.to_digit(10).unwrap() as u8);
-
-        let start_positions = grid
-            .iter()
-            .filter(|(_, v)| **v == 0)
-            .map(|(p, _)| p)
-            .collect::<Vec<_>>();
-
-        let mut result = 0;
-        for start_pos in start_positions {
-            let mut visited = HashSet::new();
-            let trailheads = get_trailheads(start_pos, &grid, &mut visited);
-            result += trailheads;
-        }
+    fn solve_one(&self, input: &BorrowedPuzzleInput) -> String {
+        let grid = input.digit_grid();
+
+        let result: usize = trails(&grid)
+            .map(|paths| {
+                paths
+                    .nodes()
+                    .filter(|(p, _)| grid.get(p) == Some(&9))
+                    .count()
+            })
+            .sum();
 
         result.to_string()
     }
@@ -133,24 +48,18 @@ impl Solution for S {
         "36"
     }
 
-    fn solve_two(&self, input: &PuzzleInput) -> String {
-        let grid = input.grid2d(|c| c.to_digit(10).unwrap() as u8);
-
-        let start_positions = grid
-            .iter()
-            .filter(|(_, v)| **v == 0)
-            .map(|(p, _)| p)
-            .collect::<Vec<_>>();
+    fn solve_two(&self, input: &BorrowedPuzzleInput) -> String {
+        let grid = input.digit_grid();
 
-        let mut result = 0;
-        for start_pos in start_positions {
-            let mut trail = Trail {
-                trail: vec![],
-                visited: HashSet::new(),
-            };
-            let trailheads = get_trailheads2(start_pos, &grid, &mut trail);
-            result += trailheads.len();
-        }
+        let result: usize = trails(&grid)
+            .map(|paths| {
+                paths
+                    .nodes()
+                    .filter(|(p, _)| grid.get(p) == Some(&9))
+                    .map(|(p, _)| paths.count_paths_to(p))
+                    .sum::<usize>()
+            })
+            .sum();
 
         result.to_string()
     }
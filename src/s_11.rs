@@ -7,7 +7,7 @@ use crate::common::*;
 pub struct S;
 
 impl Solution for S {
-    fn solve_one(&self, input: &PuzzleInput) -> String {
+    fn solve_one(&self, input: &BorrowedPuzzleInput) -> String {
         solve(input, 25).to_string()
     }
 
@@ -20,7 +20,7 @@ impl Solution for S {
         "55312"
     }
 
-    fn solve_two(&self, input: &PuzzleInput) -> String {
+    fn solve_two(&self, input: &BorrowedPuzzleInput) -> String {
         solve(input, 75).to_string()
     }
 
@@ -34,7 +34,7 @@ impl Solution for S {
     }
 }
 
-fn solve(input: &PuzzleInput, blinks: usize) -> usize {
+fn solve(input: &BorrowedPuzzleInput, blinks: usize) -> usize {
     let mut stones = input.lines[0]
         .split_whitespace()
         .map(|s| s.parse::<usize>().unwrap())
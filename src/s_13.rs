@@ -16,7 +16,7 @@ lazy_static! {
 }
 
 impl Solution for S {
-    fn solve_one(&self, input: &PuzzleInput) -> String {
+    fn solve_one(&self, input: &BorrowedPuzzleInput) -> String {
         solve(input, 0).to_string()
     }
 
@@ -43,7 +43,7 @@ Prize: X=18641, Y=10279
         "480"
     }
 
-    fn solve_two(&self, input: &PuzzleInput) -> String {
+    fn solve_two(&self, input: &BorrowedPuzzleInput) -> String {
         solve(input, 10000000000000).to_string()
     }
 
@@ -71,10 +71,10 @@ Prize: X=18641, Y=10279
     }
 }
 
-fn solve(input: &PuzzleInput, c: i64) -> i64 {
-    let get_numbers = |regex: &Regex, string: &String| -> (i64, i64) {
+fn solve(input: &BorrowedPuzzleInput, c: i64) -> i64 {
+    let get_numbers = |regex: &Regex, string: &str| -> VecI64 {
         let cap = regex.captures_iter(string).next().unwrap();
-        (
+        VecI64(
             cap.get(1).unwrap().as_str().parse::<i64>().unwrap(),
             cap.get(2).unwrap().as_str().parse::<i64>().unwrap(),
         )
@@ -88,7 +88,7 @@ fn solve(input: &PuzzleInput, c: i64) -> i64 {
     let b = Int::new_const(ctx, "b");
     let zero = Int::from_i64(ctx, 0);
     let three = Int::from_i64(ctx, 3);
-    let c = Int::from_i64(ctx, c);
+    let offset = VecI64(c, c);
 
     let mut iter = input.lines.iter();
     let mut line = iter.next();
@@ -102,16 +102,16 @@ fn solve(input: &PuzzleInput, c: i64) -> i64 {
         iter.next();
         line = iter.next();
 
-        let (ax, ay) = get_numbers(&BUTTON, button_a);
-        let (bx, by) = get_numbers(&BUTTON, button_b);
-        let (px, py) = get_numbers(&PRIZE, prize);
+        let button_a = get_numbers(&BUTTON, button_a);
+        let button_b = get_numbers(&BUTTON, button_b);
+        let prize = get_numbers(&PRIZE, prize) + offset;
 
-        let ax = Int::from_i64(ctx, ax);
-        let ay = Int::from_i64(ctx, ay);
-        let bx = Int::from_i64(ctx, bx);
-        let by = Int::from_i64(ctx, by);
-        let px = Int::from_i64(ctx, px);
-        let py = Int::from_i64(ctx, py);
+        let ax = Int::from_i64(ctx, button_a.0);
+        let ay = Int::from_i64(ctx, button_a.1);
+        let bx = Int::from_i64(ctx, button_b.0);
+        let by = Int::from_i64(ctx, button_b.1);
+        let px = Int::from_i64(ctx, prize.0);
+        let py = Int::from_i64(ctx, prize.1);
 
         let opt = Optimize::new(ctx);
 
@@ -123,14 +123,12 @@ fn solve(input: &PuzzleInput, c: i64) -> i64 {
             ctx,
             &[&Int::mul(ctx, &[&ax, &a]), &Int::mul(ctx, &[&bx, &b])],
         );
-        let px = Int::add(ctx, &[&px, &c]);
         opt.assert(&eq_1._eq(&px));
 
         let eq_2 = Int::add(
             ctx,
             &[&Int::mul(ctx, &[&ay, &a]), &Int::mul(ctx, &[&by, &b])],
         );
-        let py = Int::add(ctx, &[&py, &c]);
         opt.assert(&eq_2._eq(&py));
 
         if opt.check(&[]) == SatResult::Sat {
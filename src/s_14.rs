@@ -1,6 +1,6 @@
 use std::cmp::Ordering;
 
-use crate::common::*;
+use crate::common::{torus::Torus, *};
 use lazy_static::lazy_static;
 use regex::Regex;
 
@@ -10,38 +10,19 @@ lazy_static! {
     static ref LINE: Regex = Regex::new(r"^p=(\d+),(\d+) v=(-?\d+),(-?\d+)$").unwrap();
 }
 
-fn mod_neg(a: i32, b: i32) -> i32 {
-    ((a % b) + b) % b
-}
-
 impl Solution for S {
-    fn solve_one(&self, input: &PuzzleInput) -> String {
+    fn solve_one(&self, input: &BorrowedPuzzleInput) -> String {
         let width = 101;
         let height = 103;
-
-        let get_numbers = |regex: &Regex, string: &String| -> (VecI2, VecI2) {
-            let cap = regex.captures_iter(string).next().unwrap();
-            (
-                VecI2(
-                    cap.get(1).unwrap().as_str().parse::<i32>().unwrap(),
-                    cap.get(2).unwrap().as_str().parse::<i32>().unwrap(),
-                ),
-                VecI2(
-                    cap.get(3).unwrap().as_str().parse::<i32>().unwrap(),
-                    cap.get(4).unwrap().as_str().parse::<i32>().unwrap(),
-                ),
-            )
-        };
+        let torus = Torus::new(width, height);
 
         let mut left_top = 0;
         let mut right_top = 0;
         let mut left_bottom = 0;
         let mut right_bottom = 0;
 
-        for line in input.lines.iter() {
-            let (p, v) = get_numbers(&LINE, line);
-            let end = p + v * 100;
-            let end = VecI2(mod_neg(end.0, width), mod_neg(end.1, height));
+        for (p, v) in parse(input) {
+            let end = torus.step(&p, &v, 100);
 
             match (end.0.cmp(&(width / 2)), end.1.cmp(&(height / 2))) {
                 (Ordering::Less, Ordering::Less) => left_top += 1,
@@ -77,23 +58,12 @@ p=9,5 v=-3,-3
         "21"
     }
 
-    fn solve_two(&self, input: &PuzzleInput) -> String {
+    fn solve_two(&self, input: &BorrowedPuzzleInput) -> String {
         let width: i32 = 101;
         let height: i32 = 103;
+        let torus = Torus::new(width, height);
 
-        let get_numbers = |regex: &Regex, string: &String| -> (VecI2, VecI2) {
-            let cap = regex.captures_iter(string).next().unwrap();
-            (
-                VecI2(
-                    cap.get(1).unwrap().as_str().parse::<i32>().unwrap(),
-                    cap.get(2).unwrap().as_str().parse::<i32>().unwrap(),
-                ),
-                VecI2(
-                    cap.get(3).unwrap().as_str().parse::<i32>().unwrap(),
-                    cap.get(4).unwrap().as_str().parse::<i32>().unwrap(),
-                ),
-            )
-        };
+        let robots = parse(input);
 
         const GRID_W: usize = 3;
         const GRID_H: usize = 3;
@@ -101,16 +71,14 @@ p=9,5 v=-3,-3
         for steps in 0..=10000 {
             let mut buckets = [0; GRID_H * GRID_W];
 
-            for line in input.lines.iter() {
-                let (p, v) = get_numbers(&LINE, line);
-                let end = p + v * steps;
-                let end = VecI2(mod_neg(end.0, width), mod_neg(end.1, height));
+            for (p, v) in robots.iter() {
+                let end = torus.step(p, v, steps);
 
                 let q1 = (end.0 as f32 / width as f32 * GRID_W as f32).clamp(0.0, GRID_W as f32)
                     as usize;
                 let q2 = (end.1 as f32 / height as f32 * GRID_H as f32).clamp(0.0, GRID_H as f32)
                     as usize;
-                buckets[(q2 * GRID_W + q1) as usize] += 1;
+                buckets[q2 * GRID_W + q1] += 1;
             }
 
             let weighted_max = weighted_max(&buckets);
@@ -130,6 +98,34 @@ p=9,5 v=-3,-3
     fn expected_output_two(&self) -> &str {
         ""
     }
+
+    // Every second until the christmas tree shows up.
+    fn simulate(&self, input: &BorrowedPuzzleInput, sink: &mut dyn FrameSink) -> bool {
+        let torus = Torus::new(101, 103);
+        let robots = parse(input);
+        let last = self.solve_two(input).parse::<i64>().unwrap_or(10000);
+
+        for steps in 0..=last {
+            let mut frame = Grid2d::new(101, 103, vec!['.'; 101 * 103]);
+            for (p, v) in robots.iter() {
+                frame.set(&torus.step(p, v, steps), '#');
+            }
+            sink.frame(&frame);
+        }
+        true
+    }
+}
+
+fn parse(input: &BorrowedPuzzleInput) -> Vec<(VecI2, VecI2)> {
+    input
+        .lines
+        .iter()
+        .map(|line| {
+            let cap = LINE.captures(line).unwrap();
+            let number = |i: usize| cap.get(i).unwrap().as_str().parse::<i32>().unwrap();
+            (VecI2(number(1), number(2)), VecI2(number(3), number(4)))
+        })
+        .collect()
 }
 
 fn weighted_max(input: &[i32]) -> f32 {
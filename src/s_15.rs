@@ -33,7 +33,6 @@ pub struct S;
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub enum Entity {
     Empty,
-    Robot,
     Box,
     BoxLeft,
     BoxRight,
@@ -44,7 +43,6 @@ impl Display for Entity {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
             Entity::Empty => write!(f, "."),
-            Entity::Robot => write!(f, "@"),
             Entity::Box => write!(f, "O"),
             Entity::BoxLeft => write!(f, "["),
             Entity::BoxRight => write!(f, "]"),
@@ -54,7 +52,7 @@ impl Display for Entity {
 }
 
 impl Solution for S {
-    fn solve_one(&self, input: &PuzzleInput) -> String {
+    fn solve_one(&self, input: &BorrowedPuzzleInput) -> String {
         solve(&input.lines, |c| c.to_string()).to_string()
     }
 
@@ -66,18 +64,8 @@ impl Solution for S {
         "10092"
     }
 
-    fn solve_two(&self, input: &PuzzleInput) -> String {
-        solve(&input.lines, |c| {
-            match c {
-                '.' => "..",
-                '@' => "@.",
-                'O' => "[]",
-                '#' => "##",
-                _ => panic!("Unknown char: {}", c),
-            }
-            .to_string()
-        })
-        .to_string()
+    fn solve_two(&self, input: &BorrowedPuzzleInput) -> String {
+        solve(&input.lines, widen).to_string()
     }
 
     fn test_input_two(&self) -> &str {
@@ -87,9 +75,30 @@ impl Solution for S {
     fn expected_output_two(&self) -> &str {
         "9021"
     }
+
+    // The wide warehouse of part two.
+    fn simulate(&self, input: &BorrowedPuzzleInput, sink: &mut dyn FrameSink) -> bool {
+        run_robot(&input.lines, widen, |grid, robot| {
+            let mut frame = grid.map(|entity| entity.to_string().chars().next().unwrap());
+            frame.set(&robot, '@');
+            sink.frame(&frame);
+        });
+        true
+    }
+}
+
+fn solve(lines: &[&str], line_modifier: fn(char) -> String) -> u32 {
+    let grid = run_robot(lines, line_modifier, |_, _| {});
+    count_boxes(&grid)
 }
 
-fn solve(lines: &[String], line_modifier: fn(char) -> String) -> u32 {
+// Calls `on_move` with the warehouse and the robot position before the first and after every
+// move.
+fn run_robot(
+    lines: &[&str],
+    line_modifier: fn(char) -> String,
+    mut on_move: impl FnMut(&Grid2d<Entity>, VecI2),
+) -> Grid2d<Entity> {
     let mut grid_lines = vec![];
     let mut move_lines = vec![];
     let mut iter = lines.iter();
@@ -106,37 +115,50 @@ fn solve(lines: &[String], line_modifier: fn(char) -> String) -> u32 {
     while next_line.is_some() {
         let line = next_line.unwrap();
         next_line = iter.next();
-        move_lines.push(line.to_string());
+        move_lines.push(*line);
     }
 
-    let mut grid = parse_grid(&grid_lines);
+    let (mut grid, mut robot_pos) = parse_grid(&grid_lines);
     let moves = parse_moves(&move_lines);
 
-    let mut robot_pos = grid.find_first(|e| matches!(e, Entity::Robot)).unwrap().0;
-
+    on_move(&grid, robot_pos);
     for direction in moves {
         if can_move(&grid, robot_pos, &direction) {
             move_it(&mut grid, robot_pos, &direction);
             robot_pos = robot_pos.dir(&direction)
         }
+        on_move(&grid, robot_pos);
     }
 
-    count_boxes(&grid)
+    grid
 }
 
-fn parse_grid(grid_lines: &[String]) -> Grid2d<Entity> {
-    Grid2d::from_lines(grid_lines, |c| match c {
-        '.' => Entity::Empty,
-        '@' => Entity::Robot,
-        'O' => Entity::Box,
-        '[' => Entity::BoxLeft,
-        ']' => Entity::BoxRight,
-        '#' => Entity::Wall,
+fn widen(c: char) -> String {
+    match c {
+        '.' => "..",
+        '@' => "@.",
+        'O' => "[]",
+        '#' => "##",
         _ => panic!("Unknown char: {}", c),
-    })
+    }
+    .to_string()
+}
+
+fn parse_grid(grid_lines: &[String]) -> (Grid2d<Entity>, VecI2) {
+    let (grid, markers) =
+        Grid2d::from_lines_with_markers(grid_lines, &[Marker::One('@')], '.', |c| match c {
+            '.' => Entity::Empty,
+            'O' => Entity::Box,
+            '[' => Entity::BoxLeft,
+            ']' => Entity::BoxRight,
+            '#' => Entity::Wall,
+            _ => panic!("Unknown char: {}", c),
+        })
+        .unwrap();
+    (grid, markers.position('@').unwrap())
 }
 
-fn parse_moves(move_lines: &[String]) -> Vec<Direction> {
+fn parse_moves(move_lines: &[&str]) -> Vec<Direction> {
     move_lines
         .iter()
         .flat_map(|l| l.chars())
@@ -156,19 +178,16 @@ fn can_move(grid: &Grid2d<Entity>, pos: VecI2, direction: &Direction) -> bool {
 
     match target {
         Entity::Empty => true,
-        Entity::Robot => panic!("There must only be one robot."),
         Entity::Box => can_move(grid, new_pos, direction),
         Entity::BoxLeft => {
-            let is_horizontal = matches!(direction, Direction::Left | Direction::Right);
-            if is_horizontal {
+            if direction.is_horizontal() {
                 can_move(grid, new_pos, direction)
             } else {
                 can_move(grid, new_pos, direction) && can_move(grid, new_pos.right(), direction)
             }
         }
         Entity::BoxRight => {
-            let is_horizontal = matches!(direction, Direction::Left | Direction::Right);
-            if is_horizontal {
+            if direction.is_horizontal() {
                 can_move(grid, new_pos, direction)
             } else {
                 can_move(grid, new_pos, direction) && can_move(grid, new_pos.left(), direction)
@@ -189,15 +208,13 @@ fn move_it(grid: &mut Grid2d<Entity>, pos: VecI2, direction: &Direction) {
             grid.set(&pos, Entity::Empty);
             grid.set(&new_pos, current);
         }
-        Entity::Robot => panic!("There must only be one robot."),
         Entity::Box => {
             move_it(grid, new_pos, direction);
             grid.set(&pos, Entity::Empty);
             grid.set(&new_pos, current);
         }
         Entity::BoxLeft => {
-            let is_horizontal = matches!(direction, Direction::Left | Direction::Right);
-            if is_horizontal {
+            if direction.is_horizontal() {
                 move_it(grid, new_pos, direction);
             } else {
                 move_it(grid, new_pos, direction);
@@ -207,8 +224,7 @@ fn move_it(grid: &mut Grid2d<Entity>, pos: VecI2, direction: &Direction) {
             grid.set(&new_pos, current);
         }
         Entity::BoxRight => {
-            let is_horizontal = matches!(direction, Direction::Left | Direction::Right);
-            if is_horizontal {
+            if direction.is_horizontal() {
                 move_it(grid, new_pos, direction);
             } else {
                 move_it(grid, new_pos, direction);
@@ -1,15 +1,12 @@
-use std::{collections::HashSet, fmt::Display, hash::Hash};
+use std::{fmt::Display, hash::Hash};
 
 use crate::common::*;
-use pathfinding::prelude::{astar, astar_bag_collect};
 
 pub struct S;
 
 #[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
 pub enum Entity {
     Empty,
-    Start,
-    End,
     Wall,
 }
 
@@ -17,8 +14,6 @@ impl Display for Entity {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
             Entity::Empty => write!(f, "."),
-            Entity::Start => write!(f, "S"),
-            Entity::End => write!(f, "E"),
             Entity::Wall => write!(f, "#"),
         }
     }
@@ -32,7 +27,7 @@ struct Pos {
 
 impl Pos {
     fn distance(&self, other: &VecI2) -> u32 {
-        let manhattan_distance = self.pos.0.abs_diff(other.0) + self.pos.1.abs_diff(other.1);
+        let manhattan_distance = self.pos.manhattan(other) as u32;
 
         let rot_distance = match self.dir {
             Direction::Left => {
@@ -78,39 +73,8 @@ impl Pos {
 
     fn successors(&self, grid: &Grid2d<Entity>) -> Vec<(Pos, u32)> {
         let mut succ = vec![];
-        match self.dir {
-            Direction::Left | Direction::Right => {
-                succ.push((
-                    Pos {
-                        pos: self.pos,
-                        dir: Direction::Up,
-                    },
-                    1000,
-                ));
-                succ.push((
-                    Pos {
-                        pos: self.pos,
-                        dir: Direction::Down,
-                    },
-                    1000,
-                ));
-            }
-            Direction::Up | Direction::Down => {
-                succ.push((
-                    Pos {
-                        pos: self.pos,
-                        dir: Direction::Left,
-                    },
-                    1000,
-                ));
-                succ.push((
-                    Pos {
-                        pos: self.pos,
-                        dir: Direction::Right,
-                    },
-                    1000,
-                ))
-            }
+        for dir in [self.dir.turn_left(), self.dir.turn_right()] {
+            succ.push((Pos { pos: self.pos, dir }, 1000));
         }
         let new_pos = self.pos.dir(&self.dir);
         if let Some(new_pos_entity) = grid.get(&new_pos) {
@@ -129,11 +93,11 @@ impl Pos {
 }
 
 impl Solution for S {
-    fn solve_one(&self, input: &PuzzleInput) -> String {
+    fn solve_one(&self, input: &BorrowedPuzzleInput) -> String {
         let (grid, start, end) = parse(input);
 
-        astar(
-            &start,
+        search::astar(
+            start,
             |p| p.successors(&grid),
             |p| p.distance(&end),
             |p| p.pos == end,
@@ -166,21 +130,18 @@ impl Solution for S {
         "7036"
     }
 
-    fn solve_two(&self, input: &PuzzleInput) -> String {
+    fn solve_two(&self, input: &BorrowedPuzzleInput) -> String {
         let (grid, start, end) = parse(input);
 
-        astar_bag_collect(
-            &start,
+        search::astar_bag(
+            start,
             |p| p.successors(&grid),
             |p| p.distance(&end),
             |p| p.pos == end,
         )
-        .unwrap_or_default()
-        .0
-        .iter()
-        .flat_map(|p| p.iter())
-        .collect::<HashSet<_>>()
-        .len()
+        .map_or(0, |(paths, targets)| {
+            paths.nodes_on_paths_to(&targets).len()
+        })
         .to_string()
     }
 
@@ -208,21 +169,19 @@ impl Solution for S {
     }
 }
 
-fn parse(input: &PuzzleInput) -> (Grid2d<Entity>, Pos, VecI2) {
-    let grid = input.grid2d(|c| match c {
-        '.' => Entity::Empty,
-        '#' => Entity::Wall,
-        'S' => Entity::Start,
-        'E' => Entity::End,
-        _ => panic!("Unknown char: {}", c),
-    });
-
-    let start = grid.find_first(|e| matches!(e, Entity::Start)).unwrap().0;
-    let end = grid.find_first(|e| matches!(e, Entity::End)).unwrap().0;
+fn parse(input: &BorrowedPuzzleInput) -> (Grid2d<Entity>, Pos, VecI2) {
+    let (grid, markers) = input
+        .grid2d_with_markers(&[Marker::One('S'), Marker::One('E')], '.', |c| match c {
+            '.' => Entity::Empty,
+            '#' => Entity::Wall,
+            _ => panic!("Unknown char: {}", c),
+        })
+        .unwrap();
 
     let start = Pos {
-        pos: start,
+        pos: markers.position('S').unwrap(),
         dir: Direction::Right,
     };
+    let end = markers.position('E').unwrap();
     (grid, start, end)
 }
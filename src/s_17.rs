@@ -92,7 +92,7 @@ lazy_static! {
 }
 
 impl Solution for S {
-    fn solve_one(&self, input: &PuzzleInput) -> String {
+    fn solve_one(&self, input: &BorrowedPuzzleInput) -> String {
         let mut registers = HashMap::new();
         let mut program = vec![];
 
@@ -235,7 +235,7 @@ Program: 0,1,5,4,3,0
         "4,6,3,5,6,3,5,2,1,0"
     }
 
-    fn solve_two(&self, input: &PuzzleInput) -> String {
+    fn solve_two(&self, input: &BorrowedPuzzleInput) -> String {
         let mut registers = [0u64; 3];
         let mut program = vec![];
 
@@ -3,11 +3,11 @@ use crate::common::*;
 pub struct S;
 
 impl Solution for S {
-    fn solve_one(&self, _input: &PuzzleInput) -> String {
+    fn solve_one(&self, _input: &BorrowedPuzzleInput) -> String {
         "".to_string()
     }
 
-    fn solve_two(&self, _input: &PuzzleInput) -> String {
+    fn solve_two(&self, _input: &BorrowedPuzzleInput) -> String {
         "".to_string()
     }
 }